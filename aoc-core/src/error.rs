@@ -0,0 +1,143 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+    token: String,
+    expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, expected: &str) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            token: token.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+
+    // Locates `token` within `source`, which is expected to be a slice of it
+    // as produced by `lines`, `split` and friends.
+    pub fn at(source: &str, token: &str, expected: &str) -> Self {
+        let offset = offset_of(source, token).unwrap_or(0);
+        let preceding = &source[..offset];
+        let line = preceding.matches('\n').count() + 1;
+        let line_start = preceding.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = preceding[line_start..].chars().count() + 1;
+        Self::new(line, column, token, expected)
+    }
+
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+
+    // Moves an error found while parsing a single line onto that line of the
+    // whole input.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = line + self.line - 1;
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        write!(f, "{}:{}: unexpected ", self.line, self.column)?;
+        match self.token.as_str() {
+            "" => write!(f, "end of line")?,
+            token => write!(f, "'{token}'")?,
+        }
+        write!(f, ", expected {}", self.expected)
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_token<T: FromStr>(source: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, expected))
+}
+
+fn offset_of(source: &str, token: &str) -> Option<usize> {
+    let source_start = source.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    match token_start.checked_sub(source_start) {
+        Some(offset) if offset + token.len() <= source.len() => Some(offset),
+        _ => source.find(token),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn at_locates_token_slices() {
+        let source = "1x2x3\n4x5xy\n";
+        let token = source.lines().nth(1).unwrap().split('x').nth(2).unwrap();
+        let error = ParseError::at(source, token, "a number");
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(error.token(), "y");
+    }
+
+    #[test]
+    fn at_finds_tokens_not_sliced_from_source() {
+        let error = ParseError::at("abc\ndef", "ef", "something else");
+        assert_eq!((error.line(), error.column()), (2, 2));
+    }
+
+    #[test]
+    fn with_line_moves_line_errors() {
+        let error = ParseError::at("12 x", "x", "a number").with_line(7);
+        assert_eq!((error.line(), error.column()), (7, 4));
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new(3, 9, "foo", "a number");
+        assert_eq!(
+            error.to_string(),
+            "3:9: unexpected 'foo', expected a number"
+        );
+        let error = ParseError::new(1, 4, "", "a number").with_file("input/d02.txt");
+        assert_eq!(
+            error.to_string(),
+            "input/d02.txt:1:4: unexpected end of line, expected a number"
+        );
+    }
+
+    #[test]
+    fn parse_token_examples() {
+        assert_eq!(parse_token::<u32>("a 12", "12", "a number"), Ok(12));
+        let error = parse_token::<u32>("a 1b", "1b", "a number").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 3));
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Embedded(u8),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    // Uses the first command line argument if there is one, otherwise the
+    // default input for the day.
+    pub fn from_args(day: u8) -> Self {
+        match env::args().nth(1) {
+            Some(arg) => Self::from_arg(&arg),
+            None => Self::for_day(day),
+        }
+    }
+
+    pub fn for_day(day: u8) -> Self {
+        Self::resolve(day, env::var_os(INPUT_DIR_VAR))
+    }
+
+    fn resolve(day: u8, input_dir: Option<OsString>) -> Self {
+        if let Some(input_dir) = input_dir {
+            return InputSource::File(day_path(Path::new(&input_dir), day));
+        }
+        let path = day_path(Path::new(DEFAULT_INPUT_DIR), day);
+        if !path.exists() && embedded::get(day).is_some() {
+            return InputSource::Embedded(day);
+        }
+        InputSource::File(path)
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                Ok(buffer)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Embedded(day) => embedded::get(*day)
+                .map(|input| input.to_owned())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no embedded input")),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Embedded(day) => write!(f, "<embedded d{day:02}.txt>"),
+        }
+    }
+}
+
+// The directory inputs are read from and cached in.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn day_path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("d{day:02}.txt"))
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    pub fn get(day: u8) -> Option<&'static str> {
+        match day {
+            1 => Some(include_str!("../../input/d01.txt")),
+            2 => Some(include_str!("../../input/d02.txt")),
+            3 => Some(include_str!("../../input/d03.txt")),
+            4 => Some(include_str!("../../input/d04.txt")),
+            5 => Some(include_str!("../../input/d05.txt")),
+            6 => Some(include_str!("../../input/d06.txt")),
+            7 => Some(include_str!("../../input/d07.txt")),
+            8 => Some(include_str!("../../input/d08.txt")),
+            10 => Some(include_str!("../../input/d10.txt")),
+            11 => Some(include_str!("../../input/d11.txt")),
+            12 => Some(include_str!("../../input/d12.txt")),
+            14 => Some(include_str!("../../input/d14.txt")),
+            15 => Some(include_str!("../../input/d15.txt")),
+            17 => Some(include_str!("../../input/d17.txt")),
+            18 => Some(include_str!("../../input/d18.txt")),
+            19 => Some(include_str!("../../input/d19.txt")),
+            20 => Some(include_str!("../../input/d20.txt")),
+            21 => Some(include_str!("../../input/d21.txt")),
+            22 => Some(include_str!("../../input/d22.txt")),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+mod embedded {
+    pub fn get(_day: u8) -> Option<&'static str> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_arg_examples() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("other/d07.txt"),
+            InputSource::File(PathBuf::from("other/d07.txt"))
+        );
+    }
+
+    #[test]
+    fn resolve_uses_input_dir() {
+        let source = InputSource::resolve(7, Some(OsString::from("/tmp/inputs")));
+        assert_eq!(
+            source,
+            InputSource::File(PathBuf::from("/tmp/inputs/d07.txt"))
+        );
+        assert_eq!(source.to_string(), "/tmp/inputs/d07.txt");
+    }
+
+    #[test]
+    fn resolve_defaults_to_input_directory() {
+        let source = InputSource::resolve(25, None);
+        assert_eq!(source, InputSource::File(PathBuf::from("input/d25.txt")));
+    }
+
+    #[cfg(feature = "embed-inputs")]
+    #[test]
+    fn embedded_inputs() {
+        let source = InputSource::Embedded(1);
+        assert!(source.read().unwrap().starts_with('('));
+        assert!(InputSource::Embedded(25).read().is_err());
+    }
+}
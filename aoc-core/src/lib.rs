@@ -0,0 +1,9 @@
+mod error;
+mod input;
+mod registry;
+mod solution;
+
+pub use error::{parse_token, ParseError};
+pub use input::{day_path, input_dir, InputSource, INPUT_DIR_VAR};
+pub use registry::Registry;
+pub use solution::{DynInput, DynSolution, Solution};
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use crate::{DynSolution, Solution};
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<u8, Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) -> &mut Self {
+        self.solutions.insert(S::DAY, Box::new(solution));
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn DynSolution> {
+        self.solutions.get(&day).map(|solution| solution.as_ref())
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.values().map(|solution| solution.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_token, ParseError};
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 3;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split(',')
+                .map(|n| parse_token(input, n, "a number"))
+                .collect()
+        }
+
+        fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+            Some(input.iter().sum())
+        }
+
+        fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+            Some(input.len())
+        }
+    }
+
+    #[test]
+    fn registered_solutions_run_through_dyn_interface() {
+        let mut registry = Registry::new();
+        registry.register(Example);
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![3]);
+        assert!(registry.get(4).is_none());
+
+        let solution = registry.get(3).unwrap();
+        assert_eq!(solution.day(), 3);
+        let error = solution.parse("1,x").err().unwrap();
+        assert_eq!((error.line(), error.column()), (1, 3));
+        let input = solution.parse("1,2,3").unwrap();
+        assert_eq!(input.part_1(), Some("6".to_owned()));
+        assert_eq!(input.part_2(), Some("3".to_owned()));
+    }
+}
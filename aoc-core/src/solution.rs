@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1>;
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2>;
+}
+
+// Object safe view of a `Solution`, with answers rendered to strings, so that
+// days with different input and answer types can be driven from one registry.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn DynInput>, ParseError>;
+}
+
+pub trait DynInput {
+    fn part_1(&self) -> Option<String>;
+
+    fn part_2(&self) -> Option<String>;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> DynInput for Parsed<S> {
+    fn part_1(&self) -> Option<String> {
+        S::part_1(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self) -> Option<String> {
+        S::part_2(&self.0).map(|answer| answer.to_string())
+    }
+}
+
+impl<S: Solution + 'static> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn DynInput>, ParseError> {
+        let parsed = S::parse(input)?;
+        Ok(Box::new(Parsed::<S>(parsed)))
+    }
+}
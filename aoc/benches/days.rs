@@ -0,0 +1,60 @@
+use std::{
+    env,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_core::{day_path, InputSource, INPUT_DIR_VAR};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Parts that do not finish in a reasonable time, so cannot be sampled yet.
+const SKIPPED: &[(u8, u8)] = &[];
+
+// Benches run from the aoc package directory, so the default input directory
+// is found relative to the workspace rather than the current directory.
+fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../input"))
+}
+
+fn days(c: &mut Criterion) {
+    let registry = aoc::registry();
+    let input_dir = input_dir();
+    for solution in registry.iter() {
+        let day = solution.day();
+        let source = InputSource::File(day_path(&input_dir, day));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Skipping day {day}, {source}: {error}");
+                continue;
+            }
+        };
+        let parsed = match solution.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!(
+                    "Skipping day {day}, {}",
+                    error.with_file(&source.to_string())
+                );
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("d{day:02}"));
+        group.sample_size(10).warm_up_time(Duration::from_secs(1));
+        group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+        if !SKIPPED.contains(&(day, 1)) {
+            group.bench_function("part1", |b| b.iter(|| black_box(&parsed).part_1()));
+        }
+        if !SKIPPED.contains(&(day, 2)) {
+            group.bench_function("part2", |b| b.iter(|| black_box(&parsed).part_2()));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    // The recorded answer differs.
+    Expected(String),
+    // Ruled out by an earlier submission.
+    Rejected(Verdict),
+    Unknown,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Correct => write!(f, "correct"),
+            Check::Expected(answer) => write!(f, "incorrect, expected {answer}"),
+            Check::Rejected(verdict) => write!(f, "{verdict}"),
+            Check::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+// Keyed by "d01" style names so the file reads like the input directory.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "PartAnswers::is_empty")]
+    part1: PartAnswers,
+    #[serde(default, skip_serializing_if = "PartAnswers::is_empty")]
+    part2: PartAnswers,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    too_high: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    too_low: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    incorrect: Vec<String>,
+}
+
+impl PartAnswers {
+    fn is_empty(&self) -> bool {
+        self == &PartAnswers::default()
+    }
+
+    fn check(&self, answer: &str) -> Check {
+        if let Some(expected) = &self.answer {
+            if expected == answer {
+                return Check::Correct;
+            }
+            return Check::Expected(expected.clone());
+        }
+        if self.incorrect.iter().any(|guess| guess == answer) {
+            return Check::Rejected(Verdict::Incorrect);
+        }
+        // Hints also rule out every later guess on the wrong side of them.
+        let Ok(answer) = answer.parse::<i64>() else {
+            return Check::Unknown;
+        };
+        let bound = |guesses: &[String], rules_out: fn(i64, i64) -> bool| {
+            guesses
+                .iter()
+                .filter_map(|guess| guess.parse::<i64>().ok())
+                .any(|guess| rules_out(answer, guess))
+        };
+        if bound(&self.too_high, |answer, guess| answer >= guess) {
+            Check::Rejected(Verdict::TooHigh)
+        } else if bound(&self.too_low, |answer, guess| answer <= guess) {
+            Check::Rejected(Verdict::TooLow)
+        } else {
+            Check::Unknown
+        }
+    }
+
+    fn record(&mut self, answer: &str, verdict: Verdict) {
+        let guesses = match verdict {
+            Verdict::Correct => {
+                self.answer = Some(answer.to_owned());
+                return;
+            }
+            Verdict::TooHigh => &mut self.too_high,
+            Verdict::TooLow => &mut self.too_low,
+            Verdict::Incorrect => &mut self.incorrect,
+        };
+        if !guesses.iter().any(|guess| guess == answer) {
+            guesses.push(answer.to_owned());
+        }
+    }
+}
+
+impl Answers {
+    // A missing file is treated as having no recorded answers yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        toml::from_str(contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, contents)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Check {
+        self.days
+            .get(&day_key(day))
+            .and_then(|answers| answers.part(part))
+            .map_or(Check::Unknown, |answers| answers.check(answer))
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, verdict: Verdict) {
+        let answers = self.days.entry(day_key(day)).or_default();
+        if let Some(answers) = answers.part_mut(part) {
+            answers.record(answer, verdict);
+        }
+    }
+}
+
+impl DayAnswers {
+    fn part(&self, part: u8) -> Option<&PartAnswers> {
+        match part {
+            1 => Some(&self.part1),
+            2 => Some(&self.part2),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> Option<&mut PartAnswers> {
+        match part {
+            1 => Some(&mut self.part1),
+            2 => Some(&mut self.part2),
+            _ => None,
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("d{day:02}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Answers {
+        Answers::parse(
+            r#"
+            [d01.part1]
+            answer = "280"
+
+            [d09.part2]
+            too_high = ["900"]
+            too_low = ["100", "300"]
+            incorrect = ["abc"]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn check_examples() {
+        let answers = example();
+        assert_eq!(answers.check(1, 1, "280"), Check::Correct);
+        assert_eq!(
+            answers.check(1, 1, "281"),
+            Check::Expected("280".to_owned())
+        );
+        assert_eq!(answers.check(1, 2, "1797"), Check::Unknown);
+        assert_eq!(
+            answers.check(9, 2, "abc"),
+            Check::Rejected(Verdict::Incorrect)
+        );
+        assert_eq!(
+            answers.check(9, 2, "900"),
+            Check::Rejected(Verdict::TooHigh)
+        );
+        assert_eq!(
+            answers.check(9, 2, "1000"),
+            Check::Rejected(Verdict::TooHigh)
+        );
+        assert_eq!(answers.check(9, 2, "200"), Check::Rejected(Verdict::TooLow));
+        assert_eq!(answers.check(9, 2, "500"), Check::Unknown);
+        assert_eq!(answers.check(25, 1, "1"), Check::Unknown);
+    }
+
+    #[test]
+    fn record_round_trips() {
+        let mut answers = Answers::default();
+        answers.record(9, 2, "900", Verdict::TooHigh);
+        answers.record(9, 2, "900", Verdict::TooHigh);
+        answers.record(9, 2, "abc", Verdict::Incorrect);
+        answers.record(9, 2, "100", Verdict::TooLow);
+        answers.record(9, 2, "300", Verdict::TooLow);
+        answers.record(1, 1, "280", Verdict::Correct);
+        let contents = toml::to_string(&answers).unwrap();
+        assert_eq!(Answers::parse(&contents).unwrap(), example());
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    error, fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::day_path;
+
+use crate::http::{HttpClient, BASE_URL, SESSION_VAR};
+
+// Advent of Code asks automated tools to keep their request rate low.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    RateLimited,
+    Status(u16),
+    EmptyInput,
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "{SESSION_VAR} is not set"),
+            FetchError::RateLimited => write!(f, "rate limited by the server, try again later"),
+            FetchError::Status(status @ (400 | 401)) => {
+                write!(f, "server responded with {status}, is {SESSION_VAR} valid?")
+            }
+            FetchError::Status(status) => write!(f, "server responded with {status}"),
+            FetchError::EmptyInput => write!(f, "server returned an empty input"),
+            FetchError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        FetchError::Io(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Fetcher<C> {
+    client: C,
+    session: Option<String>,
+    base_url: String,
+    input_dir: PathBuf,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    // The session is only needed for days that are not cached yet.
+    pub fn new(client: C, session: Option<String>, input_dir: &Path) -> Self {
+        Self {
+            client,
+            session,
+            base_url: BASE_URL.to_owned(),
+            input_dir: input_dir.to_owned(),
+            min_interval: MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn fetch(&mut self, day: u8) -> Result<Fetched, FetchError> {
+        let path = day_path(&self.input_dir, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+        if let Some(elapsed) = self.last_request.map(|last| last.elapsed()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        let url = format!("{}/day/{day}/input", self.base_url);
+        let response = self.client.get(&url, session);
+        self.last_request = Some(Instant::now());
+        let response = response?;
+        match response.status {
+            200 if response.body.trim().is_empty() => return Err(FetchError::EmptyInput),
+            200 => {}
+            429 => return Err(FetchError::RateLimited),
+            status => return Err(FetchError::Status(status)),
+        }
+        // Write to a temporary file first so an interrupted download is never
+        // mistaken for a cached input.
+        fs::create_dir_all(&self.input_dir)?;
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, response.body)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::{stand_in::serve, UreqClient, USER_AGENT};
+    use std::env;
+
+    fn input_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(base_url: &str, input_dir: &Path) -> Fetcher<UreqClient> {
+        Fetcher::new(UreqClient::new(), Some("token".to_owned()), input_dir)
+            .with_base_url(base_url)
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let (base_url, server) = serve(vec![(200, "1\n2\n3\n")]);
+        let dir = input_dir("cache");
+        let mut fetcher = fetcher(&base_url, &dir);
+        let path = dir.join("d09.txt");
+
+        assert_eq!(fetcher.fetch(9).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fetcher.fetch(9).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2015/day/9/input HTTP/1.1"));
+        assert!(requests[0].contains("session=token"));
+        assert!(requests[0].contains(USER_AGENT));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (base_url, server) = serve(vec![(404, "Not Found"), (429, ""), (200, " \n")]);
+        let dir = input_dir("errors");
+        let mut fetcher = fetcher(&base_url, &dir);
+
+        assert!(matches!(fetcher.fetch(13), Err(FetchError::Status(404))));
+        assert!(matches!(fetcher.fetch(13), Err(FetchError::RateLimited)));
+        assert!(matches!(fetcher.fetch(13), Err(FetchError::EmptyInput)));
+        assert!(!dir.join("d13.txt").exists());
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn missing_session_only_matters_for_downloads() {
+        let dir = input_dir("session");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("d16.txt"), "Sue 1: cars: 9").unwrap();
+        let mut fetcher =
+            Fetcher::new(UreqClient::new(), None, &dir).with_base_url("http://127.0.0.1:9");
+
+        assert_eq!(
+            fetcher.fetch(16).unwrap(),
+            Fetched::Cached(dir.join("d16.txt"))
+        );
+        assert!(matches!(fetcher.fetch(23), Err(FetchError::MissingSession)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn requests_are_spaced_out() {
+        let (base_url, server) = serve(vec![(200, "a"), (200, "b")]);
+        let dir = input_dir("interval");
+        let min_interval = Duration::from_millis(200);
+        let mut fetcher = fetcher(&base_url, &dir).with_min_interval(min_interval);
+
+        let start = Instant::now();
+        fetcher.fetch(24).unwrap();
+        fetcher.fetch(25).unwrap();
+        assert!(start.elapsed() >= min_interval);
+        assert_eq!(server.join().unwrap().len(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
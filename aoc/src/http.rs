@@ -0,0 +1,122 @@
+use std::{env, io, time::Duration};
+
+pub const BASE_URL: &str = "https://adventofcode.com/2015";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+// Advent of Code asks automated tools to identify themselves.
+pub(crate) const USER_AGENT: &str = "github.com/Malcolm-Hall/aoc-2015";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> io::Result<Response>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<Response>;
+}
+
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self { agent }
+    }
+
+    fn request(&self, method: &str, url: &str, session: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={session}"))
+    }
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> io::Result<Response> {
+        into_response(self.request("GET", url, session).call())
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<Response> {
+        into_response(self.request("POST", url, session).send_form(form))
+    }
+}
+
+// Error statuses are still responses as far as callers are concerned.
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> io::Result<Response> {
+    match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        }),
+        Err(ureq::Error::Transport(error)) => Err(io::Error::other(error)),
+    }
+}
+
+pub fn session_from_env() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+}
+
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    // A stand-in for adventofcode.com that answers each connection with the
+    // next canned response and hands back the requests it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2015", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+}
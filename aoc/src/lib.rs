@@ -0,0 +1,49 @@
+pub mod answers;
+pub mod fetch;
+pub mod http;
+pub mod report;
+pub mod submit;
+
+use aoc_core::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register(d01::Day01)
+        .register(d02::Day02)
+        .register(d03::Day03)
+        .register(d04::Day04)
+        .register(d05::Day05)
+        .register(d06::Day06)
+        .register(d07::Day07)
+        .register(d08::Day08)
+        .register(d09::Day09)
+        .register(d10::Day10)
+        .register(d11::Day11)
+        .register(d12::Day12)
+        .register(d13::Day13)
+        .register(d14::Day14)
+        .register(d15::Day15)
+        .register(d16::Day16)
+        .register(d17::Day17)
+        .register(d18::Day18)
+        .register(d19::Day19)
+        .register(d20::Day20)
+        .register(d21::Day21)
+        .register(d22::Day22)
+        .register(d23::Day23)
+        .register(d24::Day24)
+        .register(d25::Day25);
+    registry
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registry_contains_every_day() {
+        let days = registry().days().collect::<Vec<_>>();
+        assert_eq!(days, (1..=25).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,462 @@
+use std::{
+    env,
+    io::{self, Write},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answers::{Answers, Check, ANSWERS_FILE},
+    fetch::{FetchError, Fetched, Fetcher},
+    http::{self, UreqClient},
+    report::{Format, Record, Report},
+    submit::Submitter,
+};
+use aoc_core::{input_dir, InputSource, Registry};
+
+const USAGE: &str = "Usage: aoc run <DAY|FIRST..LAST|FIRST..=LAST>... [--input <PATH|->]
+               [--format <text|json|csv>]
+       aoc run --all [--format <text|json|csv>]
+       aoc fetch <DAY|FIRST..LAST|FIRST..=LAST>...
+       aoc fetch --all
+       aoc submit <DAY> <PART> [ANSWER]
+
+Inputs are read from input/dNN.txt unless --input or the AOC_INPUT_DIR
+environment variable says otherwise. Missing inputs are downloaded into
+the same directory by fetch, using the session cookie in AOC_SESSION.
+Answers are checked against answers.toml in that directory, which submit
+keeps up to date. Without an ANSWER, submit solves the part itself.";
+
+const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(failures) if failures.is_empty() => ExitCode::SUCCESS,
+        Ok(failures) => {
+            for failure in failures {
+                eprintln!("{failure}");
+            }
+            ExitCode::FAILURE
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<Vec<String>, String> {
+    match args {
+        [command, rest @ ..] if command == "run" => {
+            let registry = aoc::registry();
+            let options = RunOptions::parse(&registry, rest)?;
+            Ok(run_days(&registry, &options))
+        }
+        [command, rest @ ..] if command == "fetch" => {
+            let days = parse_fetch_days(rest)?;
+            Ok(fetch_days(&days))
+        }
+        [command, rest @ ..] if command == "submit" => {
+            let (day, part, answer) = parse_submit_args(rest)?;
+            Ok(submit_answer(day, part, answer))
+        }
+        [command, ..] => Err(format!("Unknown command: {command}")),
+        [] => Err("Missing command".to_owned()),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RunOptions {
+    days: Vec<u8>,
+    input: Option<InputSource>,
+    format: Format,
+}
+
+impl RunOptions {
+    fn parse(registry: &Registry, args: &[String]) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut input = None;
+        let mut format = Format::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => days.extend(registry.days()),
+                "--input" => {
+                    let path = args.next().ok_or("--input expects a path or -")?;
+                    input = Some(InputSource::from_arg(path));
+                }
+                "--format" => {
+                    let name = args.next().ok_or("--format expects text, json or csv")?;
+                    format = Format::from_arg(name).ok_or(format!("Unknown format: {name}"))?;
+                }
+                selection => days.extend(parse_days(registry, selection)?),
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+        if days.is_empty() {
+            return Err("No days selected".to_owned());
+        }
+        if input.is_some() && days.len() > 1 {
+            return Err("--input can only be used with a single day".to_owned());
+        }
+        Ok(Self {
+            days,
+            input,
+            format,
+        })
+    }
+}
+
+fn parse_days(registry: &Registry, selection: &str) -> Result<Vec<u8>, String> {
+    parse_selection(selection, "has no solution", |day| {
+        registry.get(day).is_some()
+    })
+}
+
+// Ranges are narrowed down to the available days, while a single day that
+// is not available is an error.
+fn parse_selection<F: Fn(u8) -> bool>(
+    selection: &str,
+    unavailable: &str,
+    is_available: F,
+) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| day.parse::<u8>().map_err(|_| format!("Invalid day: {day}"));
+    if let Some((first, last)) = selection.split_once("..=") {
+        let range = parse_day(first)?..=parse_day(last)?;
+        Ok(range.filter(|day| is_available(*day)).collect())
+    } else if let Some((first, last)) = selection.split_once("..") {
+        let range = parse_day(first)?..parse_day(last)?;
+        Ok(range.filter(|day| is_available(*day)).collect())
+    } else {
+        let day = parse_day(selection)?;
+        if !is_available(day) {
+            return Err(format!("Day {day} {unavailable}"));
+        }
+        Ok(vec![day])
+    }
+}
+
+fn parse_fetch_days(args: &[String]) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--all" => days.extend(DAYS),
+            selection => days.extend(parse_selection(selection, "does not exist", |day| {
+                DAYS.contains(&day)
+            })?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    if days.is_empty() {
+        return Err("No days selected".to_owned());
+    }
+    Ok(days)
+}
+
+fn fetch_days(days: &[u8]) -> Vec<String> {
+    let mut failures = Vec::new();
+    let mut fetcher = Fetcher::new(UreqClient::new(), http::session_from_env(), &input_dir());
+    for &day in days {
+        match fetcher.fetch(day) {
+            Ok(Fetched::Cached(path)) => println!("Day {day:>2}: cached at {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {day:>2}: downloaded to {}", path.display())
+            }
+            Err(error) => {
+                println!("Day {day:>2}: failed");
+                failures.push(format!("Day {day}: {error}"));
+                // Carrying on would only make the server less patient.
+                if matches!(error, FetchError::RateLimited) {
+                    break;
+                }
+            }
+        }
+    }
+    failures
+}
+
+fn parse_submit_args(args: &[String]) -> Result<(u8, u8, Option<&str>), String> {
+    let (day, part, answer) = match args {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.as_str())),
+        _ => return Err("submit expects a day, a part and optionally an answer".to_owned()),
+    };
+    let day = day
+        .parse()
+        .ok()
+        .filter(|day| DAYS.contains(day))
+        .ok_or_else(|| format!("Invalid day: {day}"))?;
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        part => return Err(format!("Invalid part: {part}")),
+    };
+    Ok((day, part, answer))
+}
+
+fn submit_answer(day: u8, part: u8, answer: Option<&str>) -> Vec<String> {
+    let answer = match answer {
+        Some(answer) => answer.to_owned(),
+        None => match solve(&aoc::registry(), day, part) {
+            Ok(answer) => answer,
+            Err(error) => return vec![error],
+        },
+    };
+    let path = input_dir().join(ANSWERS_FILE);
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(error) => return vec![format!("{}: {error}", path.display())],
+    };
+    // Only ask the server about answers we cannot already judge.
+    match answers.check(day, part, &answer) {
+        Check::Correct => {
+            println!("Day {day} part {part}: {answer} is already recorded as correct");
+            return Vec::new();
+        }
+        Check::Unknown => {}
+        check => {
+            return vec![format!(
+                "Day {day} part {part}: {answer} is {check}, not submitting"
+            )]
+        }
+    }
+    let submitter = Submitter::new(UreqClient::new(), http::session_from_env());
+    let verdict = match submitter.submit(day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(error) => return vec![format!("Day {day} part {part}: {error}")],
+    };
+    println!("Day {day} part {part}: {answer} is {verdict}");
+    answers.record(day, part, &answer, verdict);
+    match answers.save(&path) {
+        Ok(()) => Vec::new(),
+        Err(error) => vec![format!("{}: {error}", path.display())],
+    }
+}
+
+fn solve(registry: &Registry, day: u8, part: u8) -> Result<String, String> {
+    let solution = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {day} has no solution"))?;
+    let source = InputSource::for_day(day);
+    let input = source
+        .read()
+        .map_err(|error| format!("{source}: {error}"))?;
+    let parsed = solution
+        .parse(&input)
+        .map_err(|error| error.with_file(&source.to_string()).to_string())?;
+    let answer = match part {
+        1 => parsed.part_1(),
+        _ => parsed.part_2(),
+    };
+    answer.ok_or_else(|| format!("Day {day} part {part} has no answer"))
+}
+
+fn run_days(registry: &Registry, options: &RunOptions) -> Vec<String> {
+    let mut failures = Vec::new();
+    // Recorded answers only hold for the inputs they were recorded against.
+    let answers = match &options.input {
+        Some(_) => Answers::default(),
+        None => {
+            let path = input_dir().join(ANSWERS_FILE);
+            Answers::load(&path).unwrap_or_else(|error| {
+                failures.push(format!("{}: {error}", path.display()));
+                Answers::default()
+            })
+        }
+    };
+    let mut report = Report::new(options.format, io::stdout().lock());
+    if let Err(error) = run_days_into(registry, options, &answers, &mut report, &mut failures) {
+        failures.push(format!("Could not write results: {error}"));
+    }
+    failures
+}
+
+fn run_days_into<W: Write>(
+    registry: &Registry,
+    options: &RunOptions,
+    answers: &Answers,
+    report: &mut Report<W>,
+    failures: &mut Vec<String>,
+) -> io::Result<()> {
+    report.start()?;
+    for solution in options.days.iter().filter_map(|day| registry.get(*day)) {
+        let day = solution.day();
+        let source = match &options.input {
+            Some(source) => source.clone(),
+            None => InputSource::for_day(day),
+        };
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(error) => {
+                let error = format!("{source}: {error}");
+                report.skipped(day, "Could not read input", &error, None)?;
+                failures.push(error);
+                continue;
+            }
+        };
+        let parsed = match solution.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                let error = error.with_file(&source.to_string()).to_string();
+                report.skipped(day, "Could not parse input", &error, Some(&input))?;
+                failures.push(error);
+                continue;
+            }
+        };
+        let (answer, elapsed) = time_part(|| parsed.part_1());
+        let record = Record::new(day, 1, answer, elapsed, &input);
+        report_part(answers, report, &record, failures)?;
+        let (answer, elapsed) = time_part(|| parsed.part_2());
+        let record = Record::new(day, 2, answer, elapsed, &input);
+        report_part(answers, report, &record, failures)?;
+    }
+    report.finish()
+}
+
+fn time_part<F: FnOnce() -> Option<String>>(solve: F) -> (Option<String>, Duration) {
+    let start = Instant::now();
+    let answer = solve();
+    (answer, start.elapsed())
+}
+
+// Answers that contradict the recorded answers are failures.
+fn report_part<W: Write>(
+    answers: &Answers,
+    report: &mut Report<W>,
+    record: &Record,
+    failures: &mut Vec<String>,
+) -> io::Result<()> {
+    let Some(answer) = &record.answer else {
+        return report.record(record, "-");
+    };
+    let (day, part) = (record.day, record.part);
+    let check = answers.check(day, part, answer);
+    if let Check::Expected(_) | Check::Rejected(_) = check {
+        failures.push(format!("Day {day} part {part}: {answer} is {check}"));
+    }
+    report.record(record, &check.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_days_examples() {
+        let registry = aoc::registry();
+        assert_eq!(parse_days(&registry, "7").unwrap(), vec![7]);
+        assert_eq!(parse_days(&registry, "22..25").unwrap(), vec![22, 23, 24]);
+        assert_eq!(
+            parse_days(&registry, "22..=26").unwrap(),
+            vec![22, 23, 24, 25]
+        );
+        assert!(parse_days(&registry, "26").is_err());
+        assert!(parse_days(&registry, "seven").is_err());
+    }
+
+    #[test]
+    fn parse_fetch_days_examples() {
+        assert_eq!(
+            parse_fetch_days(&args(&["9", "13", "9"])).unwrap(),
+            vec![9, 13]
+        );
+        assert_eq!(
+            parse_fetch_days(&args(&["23..30"])).unwrap(),
+            vec![23, 24, 25]
+        );
+        assert_eq!(parse_fetch_days(&args(&["--all"])).unwrap().len(), 25);
+        assert!(parse_fetch_days(&args(&["26"])).is_err());
+        assert!(parse_fetch_days(&args(&[])).is_err());
+    }
+
+    #[test]
+    fn parse_submit_args_examples() {
+        let submit_args = args(&["9", "2", "141"]);
+        assert_eq!(
+            parse_submit_args(&submit_args).unwrap(),
+            (9, 2, Some("141"))
+        );
+        assert_eq!(parse_submit_args(&args(&["1", "1"])).unwrap(), (1, 1, None));
+        assert!(parse_submit_args(&args(&["26", "1"])).is_err());
+        assert!(parse_submit_args(&args(&["1", "3"])).is_err());
+        assert!(parse_submit_args(&args(&["1"])).is_err());
+    }
+
+    #[test]
+    fn parse_run_options() {
+        let registry = aoc::registry();
+        let options = RunOptions::parse(&registry, &args(&["3", "1..=2", "2"])).unwrap();
+        assert_eq!(options.days, vec![1, 2, 3]);
+        assert_eq!(options.input, None);
+
+        let options = RunOptions::parse(&registry, &args(&["4", "--input", "-"])).unwrap();
+        assert_eq!(options.days, vec![4]);
+        assert_eq!(options.input, Some(InputSource::Stdin));
+
+        let options = RunOptions::parse(&registry, &args(&["--all"])).unwrap();
+        assert_eq!(options.days, registry.days().collect::<Vec<_>>());
+        assert_eq!(options.format, Format::Text);
+
+        let options = RunOptions::parse(&registry, &args(&["1", "--format", "csv"])).unwrap();
+        assert_eq!(options.format, Format::Csv);
+        assert!(RunOptions::parse(&registry, &args(&["1", "--format", "xml"])).is_err());
+
+        assert!(RunOptions::parse(&registry, &args(&["1..=3", "--input", "-"])).is_err());
+        assert!(RunOptions::parse(&registry, &args(&[])).is_err());
+    }
+
+    fn run_missing_input(format: Format) -> (String, Vec<String>) {
+        let options = RunOptions {
+            days: vec![1],
+            input: Some(InputSource::File("no/such/d01.txt".into())),
+            format,
+        };
+        let mut out = Vec::new();
+        let mut report = Report::new(format, &mut out);
+        let mut failures = Vec::new();
+        run_days_into(
+            &aoc::registry(),
+            &options,
+            &Answers::default(),
+            &mut report,
+            &mut failures,
+        )
+        .unwrap();
+        (String::from_utf8(out).unwrap(), failures)
+    }
+
+    #[test]
+    fn missing_input_json() {
+        let (json, failures) = run_missing_input(Format::Json);
+        assert_eq!(failures.len(), 1);
+        let records = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        let records = records.as_array().unwrap();
+        assert_eq!(records.len(), 2);
+        for (part, record) in (1..).zip(records) {
+            assert_eq!(record["day"], 1);
+            assert_eq!(record["part"], part);
+            assert_eq!(record["status"], "error");
+            assert_eq!(record["error"], failures[0].as_str());
+            assert!(record["answer"].is_null() && record["elapsed_ns"].is_null());
+            assert!(record["input_hash"].is_null());
+        }
+    }
+
+    #[test]
+    fn missing_input_csv() {
+        let (csv, failures) = run_missing_input(Format::Csv);
+        assert!(failures[0].starts_with("no/such/d01.txt: "));
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], format!("1,1,,,,error,{}", failures[0]));
+        assert_eq!(lines[2], format!("1,2,,,,error,{}", failures[0]));
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u8, part: u8, answer: Option<String>, elapsed: Duration, input: &str) -> Self {
+        Self {
+            day,
+            part,
+            answer,
+            elapsed_ns: Some(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX)),
+            input_hash: Some(input_hash(input)),
+            status: Status::Ok,
+            error: None,
+        }
+    }
+
+    // A part that never ran, with the input's hash if it could be read.
+    pub fn failed(day: u8, part: u8, error: &str, input: Option<&str>) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            elapsed_ns: None,
+            input_hash: input.map(input_hash),
+            status: Status::Error,
+            error: Some(error.to_owned()),
+        }
+    }
+}
+
+// MD5 is plenty to tell inputs apart, and is already used by day 4.
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", md5::compute(input))
+}
+
+// Writes records as they are produced, so slow days show up in the text
+// table straight away. JSON is written as an array with one record per line
+// to keep the output easy to diff.
+pub struct Report<W> {
+    format: Format,
+    out: W,
+    records: usize,
+}
+
+impl<W: Write> Report<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        Self {
+            format,
+            out,
+            records: 0,
+        }
+    }
+
+    pub fn start(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.text_row("Day", "Part", "Answer", "Elapsed", "Check"),
+            Format::Json => write!(self.out, "["),
+            Format::Csv => writeln!(
+                self.out,
+                "day,part,answer,elapsed_ns,input_hash,status,error"
+            ),
+        }
+    }
+
+    // Days that could not be read or parsed take a single row of the text
+    // table, but a failed record for each part in the machine readable
+    // formats, so every requested part shows up.
+    pub fn skipped(
+        &mut self,
+        day: u8,
+        reason: &str,
+        error: &str,
+        input: Option<&str>,
+    ) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.text_row(&day.to_string(), "-", reason, "-", "-"),
+            Format::Json | Format::Csv => {
+                for part in 1..=2 {
+                    self.record(&Record::failed(day, part, error, input), "-")?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn record(&mut self, record: &Record, check: &str) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                let answer = record.answer.as_deref().unwrap_or("No answer");
+                let elapsed = record.elapsed_ns.map_or("-".to_owned(), |elapsed| {
+                    format!("{:.2?}", Duration::from_nanos(elapsed))
+                });
+                self.text_row(
+                    &record.day.to_string(),
+                    &record.part.to_string(),
+                    answer,
+                    &elapsed,
+                    check,
+                )?;
+            }
+            Format::Json => {
+                let separator = if self.records == 0 { "" } else { "," };
+                let record = serde_json::to_string(record).map_err(io::Error::other)?;
+                write!(self.out, "{separator}\n  {record}")?;
+            }
+            Format::Csv => writeln!(
+                self.out,
+                "{},{},{},{},{},{},{}",
+                record.day,
+                record.part,
+                csv_field(record.answer.as_deref().unwrap_or("")),
+                record
+                    .elapsed_ns
+                    .map_or(String::new(), |elapsed| elapsed.to_string()),
+                record.input_hash.as_deref().unwrap_or(""),
+                record.status.as_str(),
+                csv_field(record.error.as_deref().unwrap_or(""))
+            )?,
+        }
+        self.records += 1;
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Text | Format::Csv => Ok(()),
+            Format::Json if self.records == 0 => writeln!(self.out, "]"),
+            Format::Json => writeln!(self.out, "\n]"),
+        }
+    }
+
+    fn text_row(
+        &mut self,
+        day: &str,
+        part: &str,
+        answer: &str,
+        elapsed: &str,
+        check: &str,
+    ) -> io::Result<()> {
+        writeln!(
+            self.out,
+            "{day:>3}  {part:>4}  {answer:<20}  {elapsed:>12}  {check}"
+        )
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn examples() -> Vec<Record> {
+        vec![
+            Record::new(
+                1,
+                1,
+                Some("280".to_owned()),
+                Duration::from_micros(45),
+                "(()",
+            ),
+            Record::new(1, 2, None, Duration::from_nanos(500), "(()"),
+        ]
+    }
+
+    fn report(format: Format, records: &[Record]) -> String {
+        let mut report = Report::new(format, Vec::new());
+        report.start().unwrap();
+        for record in records {
+            report.record(record, "unknown").unwrap();
+        }
+        report.finish().unwrap();
+        String::from_utf8(report.out).unwrap()
+    }
+
+    #[test]
+    fn input_hash_examples() {
+        assert_eq!(input_hash(""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            input_hash("abcdef609043"),
+            "000001dbbfa3a5c83a2d506429c7b00e"
+        );
+    }
+
+    #[test]
+    fn json_examples() {
+        let json = report(Format::Json, &examples());
+        let hash = input_hash("(()");
+        assert_eq!(
+            json,
+            format!(
+                "[\n  \
+                {{\"day\":1,\"part\":1,\"answer\":\"280\",\"elapsed_ns\":45000,\"input_hash\":\"{hash}\",\"status\":\"ok\",\"error\":null}},\n  \
+                {{\"day\":1,\"part\":2,\"answer\":null,\"elapsed_ns\":500,\"input_hash\":\"{hash}\",\"status\":\"ok\",\"error\":null}}\n]\n"
+            )
+        );
+        assert!(serde_json::from_str::<serde_json::Value>(&json)
+            .unwrap()
+            .is_array());
+        assert_eq!(report(Format::Json, &[]), "[]\n");
+    }
+
+    #[test]
+    fn csv_examples() {
+        let hash = input_hash("(()");
+        assert_eq!(
+            report(Format::Csv, &examples()),
+            format!(
+                "day,part,answer,elapsed_ns,input_hash,status,error\n1,1,280,45000,{hash},ok,\n1,2,,500,{hash},ok,\n"
+            )
+        );
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn skipped_examples() {
+        let mut report = Report::new(Format::Csv, Vec::new());
+        report.start().unwrap();
+        report
+            .skipped(2, "Could not parse input", "d02.txt: bad, box", Some("1x2"))
+            .unwrap();
+        let hash = input_hash("1x2");
+        assert_eq!(
+            String::from_utf8(report.out).unwrap(),
+            format!(
+                "day,part,answer,elapsed_ns,input_hash,status,error\n\
+                 2,1,,,{hash},error,\"d02.txt: bad, box\"\n\
+                 2,2,,,{hash},error,\"d02.txt: bad, box\"\n"
+            )
+        );
+        let mut report = Report::new(Format::Text, Vec::new());
+        report
+            .skipped(2, "Could not parse input", "bad", None)
+            .unwrap();
+        assert_eq!(String::from_utf8(report.out).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn text_examples() {
+        let text = report(Format::Text, &examples());
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Day  Part  Answer"));
+        assert!(lines[1].contains("280") && lines[1].contains("45.00µs"));
+        assert!(lines[2].contains("No answer"));
+    }
+}
@@ -0,0 +1,171 @@
+use std::{error, fmt, io};
+
+use crate::{
+    answers::Verdict,
+    http::{HttpClient, BASE_URL, SESSION_VAR},
+};
+
+#[derive(Debug)]
+pub enum SubmitError {
+    MissingSession,
+    // Carries the wait the server asked for, when it said.
+    TooSoon(Option<String>),
+    WrongLevel,
+    Status(u16),
+    UnrecognisedResponse,
+    Io(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::MissingSession => write!(f, "{SESSION_VAR} is not set"),
+            SubmitError::TooSoon(Some(wait)) => write!(f, "answered too recently, wait {wait}"),
+            SubmitError::TooSoon(None) => write!(f, "answered too recently"),
+            SubmitError::WrongLevel => {
+                write!(f, "that part is locked or has already been solved")
+            }
+            SubmitError::Status(status) => write!(f, "server responded with {status}"),
+            SubmitError::UnrecognisedResponse => write!(f, "could not understand the response"),
+            SubmitError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl error::Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(error: io::Error) -> Self {
+        SubmitError::Io(error)
+    }
+}
+
+pub struct Submitter<C> {
+    client: C,
+    session: Option<String>,
+    base_url: String,
+}
+
+impl<C: HttpClient> Submitter<C> {
+    pub fn new(client: C, session: Option<String>) -> Self {
+        Self {
+            client,
+            session,
+            base_url: BASE_URL.to_owned(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, SubmitError> {
+        let session = self.session.as_deref().ok_or(SubmitError::MissingSession)?;
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let response = self
+            .client
+            .post(&url, session, &[("level", &level), ("answer", answer)])?;
+        if response.status != 200 {
+            return Err(SubmitError::Status(response.status));
+        }
+        parse_verdict(&response.body)
+    }
+}
+
+fn parse_verdict(body: &str) -> Result<Verdict, SubmitError> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Incorrect)
+        }
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_owned());
+        Err(SubmitError::TooSoon(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Err(SubmitError::WrongLevel)
+    } else {
+        Err(SubmitError::UnrecognisedResponse)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::{stand_in::serve, UreqClient};
+
+    #[test]
+    fn parse_verdict_examples() {
+        let verdict = |body| parse_verdict(body).ok();
+        assert_eq!(
+            verdict("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            verdict("<p>That's not the right answer; your answer is too high.</p>"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            verdict("<p>That's not the right answer; your answer is too low.</p>"),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            verdict("<p>That's not the right answer.</p>"),
+            Some(Verdict::Incorrect)
+        );
+        assert!(matches!(
+            parse_verdict("<p>You gave an answer too recently. You have 41s left to wait.</p>"),
+            Err(SubmitError::TooSoon(Some(wait))) if wait == "41s"
+        ));
+        assert!(matches!(
+            parse_verdict("<p>You don't seem to be solving the right level.</p>"),
+            Err(SubmitError::WrongLevel)
+        ));
+        assert!(matches!(
+            parse_verdict("<html></html>"),
+            Err(SubmitError::UnrecognisedResponse)
+        ));
+    }
+
+    #[test]
+    fn submits_to_stand_in_server() {
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>",
+            ),
+            (500, ""),
+        ]);
+        let submitter =
+            Submitter::new(UreqClient::new(), Some("token".to_owned())).with_base_url(&base_url);
+
+        assert_eq!(submitter.submit(9, 2, "141").unwrap(), Verdict::TooLow);
+        assert!(matches!(
+            submitter.submit(9, 2, "142"),
+            Err(SubmitError::Status(500))
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2015/day/9/answer HTTP/1.1"));
+        assert!(requests[0].contains("session=token"));
+        assert!(requests[0].ends_with("level=2&answer=141"));
+    }
+
+    #[test]
+    fn missing_session() {
+        let submitter = Submitter::new(UreqClient::new(), None).with_base_url("http://127.0.0.1:9");
+        assert!(matches!(
+            submitter.submit(1, 1, "280"),
+            Err(SubmitError::MissingSession)
+        ));
+    }
+}
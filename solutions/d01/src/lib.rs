@@ -0,0 +1,179 @@
+use std::{error, fmt, ops::RangeInclusive};
+
+use aoc_core::{ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Step>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        tokenize(input).map_err(|error| {
+            let end = error.offset + error.symbol.len_utf8();
+            ParseError::at(input, &input[error.offset..end], "'(' or ')'")
+        })
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        part_2(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidSymbol {
+    pub offset: usize,
+    pub symbol: char,
+}
+
+impl fmt::Display for InvalidSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid symbol {:?} at byte offset {}",
+            self.symbol, self.offset
+        )
+    }
+}
+
+impl error::Error for InvalidSymbol {}
+
+// Whitespace, such as the trailing newline many editors add, is skipped.
+pub fn tokenize(input: &str) -> Result<Vec<Step>, InvalidSymbol> {
+    input
+        .char_indices()
+        .filter(|(_, symbol)| !symbol.is_whitespace())
+        .map(|(offset, symbol)| match symbol {
+            '(' => Ok(Step::Up),
+            ')' => Ok(Step::Down),
+            _ => Err(InvalidSymbol { offset, symbol }),
+        })
+        .collect()
+}
+
+// The floor Santa is on after each step, starting from the ground floor.
+pub fn floors(steps: &[Step]) -> impl Iterator<Item = i32> + '_ {
+    steps.iter().scan(0, |floor, step| {
+        match step {
+            Step::Up => *floor += 1,
+            Step::Down => *floor -= 1,
+        }
+        Some(*floor)
+    })
+}
+
+// Every floor Santa passes through, including the ground floor he starts on.
+pub fn floor_range(steps: &[Step]) -> RangeInclusive<i32> {
+    let (lowest, highest) = floors(steps).fold((0, 0), |(lowest, highest), floor| {
+        (lowest.min(floor), highest.max(floor))
+    });
+    lowest..=highest
+}
+
+// The 1-based position of the step that first reaches `floor`, or 0 for the
+// ground floor Santa starts on.
+pub fn first_visit(steps: &[Step], floor: i32) -> Option<usize> {
+    if floor == 0 {
+        return Some(0);
+    }
+    floors(steps)
+        .position(|current| current == floor)
+        .map(|idx| idx + 1)
+}
+
+fn part_1(steps: &[Step]) -> i32 {
+    floors(steps).last().unwrap_or(0)
+}
+
+fn part_2(steps: &[Step]) -> Option<usize> {
+    first_visit(steps, -1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_examples() {
+        let examples = [
+            ("(())", 0),
+            ("()()", 0),
+            ("(((", 3),
+            ("(()(()(", 3),
+            ("))(((((", 3),
+            ("())", -1),
+            ("))(", -1),
+            (")))", -3),
+            (")())())", -3),
+        ];
+        for (input, expected) in examples {
+            assert_eq!(part_1(&tokenize(input).unwrap()), expected)
+        }
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = [(")", 1), ("()())", 5)];
+        for (input, expected) in examples {
+            assert_eq!(part_2(&tokenize(input).unwrap()).unwrap(), expected)
+        }
+    }
+
+    #[test]
+    fn tokenize_skips_whitespace() {
+        assert_eq!(
+            tokenize("( )\n)\r\n").unwrap(),
+            vec![Step::Up, Step::Down, Step::Down]
+        );
+        assert_eq!(part_2(&tokenize("() \n())").unwrap()), Some(5));
+    }
+
+    #[test]
+    fn tokenize_errors() {
+        let error = tokenize("(()\n)x(").unwrap_err();
+        assert_eq!(
+            error,
+            InvalidSymbol {
+                offset: 5,
+                symbol: 'x'
+            }
+        );
+        assert_eq!(error.to_string(), "invalid symbol 'x' at byte offset 5");
+        let error = tokenize("(é").unwrap_err();
+        assert_eq!(
+            error,
+            InvalidSymbol {
+                offset: 1,
+                symbol: 'é'
+            }
+        );
+
+        let error = Day01::parse("((\n)]").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (2, 2, "]"));
+    }
+
+    #[test]
+    fn floor_trace_examples() {
+        let steps = tokenize("(()))(").unwrap();
+        assert_eq!(floors(&steps).collect::<Vec<_>>(), vec![1, 2, 1, 0, -1, 0]);
+        assert_eq!(floor_range(&steps), -1..=2);
+        assert_eq!(floor_range(&[]), 0..=0);
+        assert_eq!(first_visit(&steps, 2), Some(2));
+        assert_eq!(first_visit(&steps, -1), Some(5));
+        assert_eq!(first_visit(&steps, 0), Some(0));
+        assert_eq!(first_visit(&steps, 3), None);
+    }
+}
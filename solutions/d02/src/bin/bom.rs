@@ -0,0 +1,47 @@
+use std::{env, process::ExitCode};
+
+use aoc_core::InputSource;
+use d02::bom::{BillOfMaterials, Unit};
+
+const USAGE: &str = "Usage: bom [--csv] [--unit ft|in|cm|m] ORDER|-";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match bom(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn bom(mut args: &[String]) -> Result<(), String> {
+    let mut csv = false;
+    let mut unit = Unit::default();
+    loop {
+        match args {
+            [flag, rest @ ..] if flag == "--csv" => {
+                csv = true;
+                args = rest;
+            }
+            [flag, symbol, rest @ ..] if flag == "--unit" => {
+                unit = Unit::from_symbol(symbol).ok_or(format!("Invalid unit: {symbol}"))?;
+                args = rest;
+            }
+            _ => break,
+        }
+    }
+    let source = InputSource::from_arg(args.first().ok_or("Missing order")?);
+    let input = source
+        .read()
+        .map_err(|error| format!("{source}: {error}"))?;
+    let order = BillOfMaterials::parse(&input).map_err(|error| format!("{source}: {error}"))?;
+    if csv {
+        print!("{}", order.csv_report(unit));
+    } else {
+        print!("{}", order.text_report(unit));
+    }
+    Ok(())
+}
@@ -0,0 +1,359 @@
+use std::{fmt::Write, iter::Sum, ops::Add};
+
+use aoc_core::{parse_token, ParseError};
+
+use super::OrderedSides;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Unit {
+    #[default]
+    Feet,
+    Inches,
+    Centimetres,
+    Metres,
+}
+
+impl Unit {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "ft" => Some(Unit::Feet),
+            "in" => Some(Unit::Inches),
+            "cm" => Some(Unit::Centimetres),
+            "m" => Some(Unit::Metres),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Feet => "ft",
+            Unit::Inches => "in",
+            Unit::Centimetres => "cm",
+            Unit::Metres => "m",
+        }
+    }
+
+    fn in_metres(self) -> f64 {
+        match self {
+            Unit::Feet => 0.3048,
+            Unit::Inches => 0.0254,
+            Unit::Centimetres => 0.01,
+            Unit::Metres => 1.0,
+        }
+    }
+}
+
+// Lengths, areas and volumes all in the same unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Materials {
+    pub paper: f64,
+    pub ribbon: f64,
+    pub bow: f64,
+    pub volume: f64,
+}
+
+impl Materials {
+    fn scale(self, factor: f64) -> Self {
+        Materials {
+            paper: self.paper * factor,
+            ribbon: self.ribbon * factor,
+            bow: self.bow * factor,
+            volume: self.volume * factor,
+        }
+    }
+}
+
+impl Add for Materials {
+    type Output = Materials;
+
+    fn add(self, other: Materials) -> Materials {
+        Materials {
+            paper: self.paper + other.paper,
+            ribbon: self.ribbon + other.ribbon,
+            bow: self.bow + other.bow,
+            volume: self.volume + other.volume,
+        }
+    }
+}
+
+impl Sum for Materials {
+    fn sum<I: Iterator<Item = Materials>>(iter: I) -> Materials {
+        iter.fold(Materials::default(), Add::add)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BoxSpec {
+    quantity: u32,
+    sides: OrderedSides,
+    unit: Unit,
+}
+
+impl BoxSpec {
+    // Parses `[QUANTITY *] LxWxH [UNIT]`, where the unit is one of ft, in, cm
+    // or m and defaults to feet like the puzzle.
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (quantity, rest) = match line.split_once('*') {
+            Some((quantity, rest)) => (parse_token(line, quantity.trim(), "a quantity")?, rest),
+            None => (1, line),
+        };
+        let mut words = rest.split_whitespace();
+        let dimensions = words
+            .next()
+            .ok_or_else(|| ParseError::at(line, &line[line.len()..], "box dimensions"))?;
+        let unit = match words.next() {
+            Some(symbol) => Unit::from_symbol(symbol)
+                .ok_or_else(|| ParseError::at(line, symbol, "a unit of ft, in, cm or m"))?,
+            None => Unit::default(),
+        };
+        if let Some(extra) = words.next() {
+            return Err(ParseError::at(line, extra, "the end of the line"));
+        }
+        let sides = OrderedSides::from_str_in(line, dimensions)?;
+        Ok(BoxSpec {
+            quantity,
+            sides,
+            unit,
+        })
+    }
+
+    // Materials for a single box, following the puzzle's rules but converted
+    // from the box's unit into `unit`. The bow takes a foot of ribbon for every
+    // cubic foot of the box, whatever unit the box is measured in.
+    pub fn materials(&self, unit: Unit) -> Materials {
+        let OrderedSides { min, mid, max } = self.sides;
+        let (min, mid, max) = (f64::from(min), f64::from(mid), f64::from(max));
+        let factor = self.unit.in_metres() / unit.in_metres();
+        let cubic_feet = min * mid * max * (self.unit.in_metres() / Unit::Feet.in_metres()).powi(3);
+        Materials {
+            paper: (3.0 * min * mid + 2.0 * (mid * max + max * min)) * factor.powi(2),
+            ribbon: 2.0 * (min + mid) * factor,
+            bow: cubic_feet * Unit::Feet.in_metres() / unit.in_metres(),
+            volume: min * mid * max * factor.powi(3),
+        }
+    }
+
+    pub fn line_materials(&self, unit: Unit) -> Materials {
+        self.materials(unit).scale(f64::from(self.quantity))
+    }
+
+    fn dimensions(&self) -> String {
+        let OrderedSides { min, mid, max } = self.sides;
+        format!("{min}x{mid}x{max}")
+    }
+}
+
+struct Row {
+    quantity: u32,
+    dimensions: String,
+    unit: &'static str,
+    materials: Materials,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BillOfMaterials {
+    boxes: Vec<BoxSpec>,
+}
+
+impl BillOfMaterials {
+    // Blank lines are skipped.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let boxes = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| BoxSpec::parse(line).map_err(|error| error.with_line(idx + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(BillOfMaterials { boxes })
+    }
+
+    pub fn boxes(&self) -> &[BoxSpec] {
+        &self.boxes
+    }
+
+    pub fn quantity(&self) -> u32 {
+        self.boxes.iter().map(|spec| spec.quantity).sum()
+    }
+
+    pub fn total(&self, unit: Unit) -> Materials {
+        self.boxes
+            .iter()
+            .map(|spec| spec.line_materials(unit))
+            .sum()
+    }
+
+    // One row per line of the order, in `unit`, followed by the total.
+    fn rows<'a>(&'a self, unit: Unit, total: &'a str) -> impl Iterator<Item = Row> + 'a {
+        let lines = self.boxes.iter().map(move |spec| Row {
+            quantity: spec.quantity,
+            dimensions: spec.dimensions(),
+            unit: spec.unit.symbol(),
+            materials: spec.line_materials(unit),
+        });
+        lines.chain(std::iter::once_with(move || Row {
+            quantity: self.quantity(),
+            dimensions: total.to_owned(),
+            unit: unit.symbol(),
+            materials: self.total(unit),
+        }))
+    }
+
+    pub fn text_report(&self, unit: Unit) -> String {
+        let symbol = unit.symbol();
+        let mut report = format!(
+            "{:>4}  {:<12}  {:>4}  {:>14}  {:>12}  {:>12}  {:>14}\n",
+            "Qty",
+            "Box",
+            "Unit",
+            format!("Paper ({symbol}²)"),
+            format!("Ribbon ({symbol})"),
+            format!("Bow ({symbol})"),
+            format!("Volume ({symbol}³)"),
+        );
+        for Row {
+            quantity,
+            dimensions,
+            unit: box_unit,
+            materials,
+        } in self.rows(unit, "Total")
+        {
+            let Materials {
+                paper,
+                ribbon,
+                bow,
+                volume,
+            } = materials;
+            writeln!(
+                report,
+                "{quantity:>4}  {dimensions:<12}  {box_unit:>4}  {paper:>14.2}  {ribbon:>12.2}  {bow:>12.2}  {volume:>14.2}"
+            )
+            .unwrap();
+        }
+        report
+    }
+
+    pub fn csv_report(&self, unit: Unit) -> String {
+        let mut report = format!(
+            "quantity,box,unit,paper_{0}2,ribbon_{0},bow_{0},volume_{0}3\n",
+            unit.symbol()
+        );
+        for Row {
+            quantity,
+            dimensions,
+            unit: box_unit,
+            materials,
+        } in self.rows(unit, "total")
+        {
+            let Materials {
+                paper,
+                ribbon,
+                bow,
+                volume,
+            } = materials;
+            writeln!(
+                report,
+                "{quantity},{dimensions},{box_unit},{paper},{ribbon},{bow},{volume}"
+            )
+            .unwrap();
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> BillOfMaterials {
+        BillOfMaterials::parse("2x3x4\n\n3 * 1x1x10 ft\n2*12x24x36 in\n").unwrap()
+    }
+
+    #[test]
+    fn parse_examples() {
+        let order = example();
+        assert_eq!(order.boxes().len(), 3);
+        assert_eq!(order.quantity(), 6);
+        assert_eq!(
+            order.boxes()[2],
+            BoxSpec {
+                quantity: 2,
+                sides: OrderedSides::new(12, 24, 36),
+                unit: Unit::Inches
+            }
+        );
+    }
+
+    #[test]
+    fn materials_match_the_puzzle() {
+        let order = example();
+        let first = order.boxes()[0].materials(Unit::Feet);
+        assert_eq!(first.paper, 58.0);
+        assert_eq!(first.ribbon + first.bow, 34.0);
+        assert_eq!(first.volume, 24.0);
+        let second = order.boxes()[1].line_materials(Unit::Feet);
+        assert_eq!(second.paper, 3.0 * 43.0);
+        assert_eq!(second.ribbon + second.bow, 3.0 * 14.0);
+    }
+
+    #[test]
+    fn materials_convert_units() {
+        let spec = BoxSpec::parse("12x24x36 in").unwrap();
+        let feet = spec.materials(Unit::Feet);
+        // A 1x2x3 foot box.
+        assert!((feet.paper - 3.0 * 2.0 - 2.0 * (6.0 + 3.0)).abs() < 1e-9);
+        assert!((feet.ribbon - 6.0).abs() < 1e-9);
+        assert!((feet.volume - 6.0).abs() < 1e-9);
+        // A foot of bow for each of its six cubic feet, measured in inches too.
+        assert!((feet.bow - 6.0).abs() < 1e-9);
+        assert!((spec.materials(Unit::Inches).bow - 72.0).abs() < 1e-9);
+        let metres = BoxSpec::parse("100x100x100 cm")
+            .unwrap()
+            .materials(Unit::Metres);
+        assert!((metres.volume - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn total_examples() {
+        let order = BillOfMaterials::parse("2x3x4\n3*1x1x10").unwrap();
+        let total = order.total(Unit::Feet);
+        assert_eq!(total.paper, 58.0 + 3.0 * 43.0);
+        assert_eq!(total.ribbon + total.bow, 34.0 + 3.0 * 14.0);
+        assert_eq!(total.volume, 24.0 + 30.0);
+    }
+
+    #[test]
+    fn report_examples() {
+        let order = BillOfMaterials::parse("2x3x4\n3*1x1x10").unwrap();
+        assert_eq!(
+            order.csv_report(Unit::Feet),
+            "quantity,box,unit,paper_ft2,ribbon_ft,bow_ft,volume_ft3
+1,2x3x4,ft,58,10,24,24
+3,1x1x10,ft,129,12,30,30
+4,total,ft,187,22,54,54
+"
+        );
+        let text = order.text_report(Unit::Feet);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("Paper (ft²)"));
+        assert!(lines[3].starts_with("   4  Total"));
+        assert!(lines[3].ends_with("54.00"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = BillOfMaterials::parse("2x3x4\nmany * 2x3x4").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (2, 1, "many")
+        );
+        let error = BillOfMaterials::parse("2x3x4 yd").unwrap_err();
+        assert_eq!((error.column(), error.token()), (7, "yd"));
+        let error = BillOfMaterials::parse("2 * 2x3").unwrap_err();
+        assert_eq!((error.column(), error.token()), (5, "2x3"));
+        let error = BillOfMaterials::parse("4 * 2xax4 cm").unwrap_err();
+        assert_eq!((error.column(), error.token()), (7, "a"));
+        let error = BillOfMaterials::parse("2x3x4 ft extra").unwrap_err();
+        assert_eq!(error.token(), "extra");
+    }
+}
@@ -0,0 +1,134 @@
+pub mod bom;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<OrderedSides>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(total_wrapping_paper(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(total_ribbon(input))
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct OrderedSides {
+    min: u32,
+    mid: u32,
+    max: u32,
+}
+
+impl OrderedSides {
+    fn new(min: u32, mid: u32, max: u32) -> Self {
+        Self { min, mid, max }
+    }
+
+    fn from_unordered(sides: &mut [u32]) -> Option<Self> {
+        sides.sort_unstable();
+        match &sides[..] {
+            [min, mid, max] => Some(Self::new(*min, *mid, *max)),
+            _ => None,
+        }
+    }
+
+    fn wrapping_paper_area(&self) -> u32 {
+        let Self { min, mid, max } = *self;
+        3 * (min * mid) + 2 * (mid * max + max * min)
+    }
+
+    fn ribbon_length(&self) -> u32 {
+        let Self { min, mid, max } = *self;
+        2 * (min + mid) + min * mid * max
+    }
+
+    fn from_str(dimensions: &str) -> Result<Self, ParseError> {
+        Self::from_str_in(dimensions, dimensions)
+    }
+
+    // Parses `dimensions`, a slice of `source`, with errors located in
+    // `source`.
+    fn from_str_in(source: &str, dimensions: &str) -> Result<Self, ParseError> {
+        let mut sides = dimensions
+            .split('x')
+            .map(|s| parse_token(source, s, "a side length"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_unordered(&mut sides)
+            .ok_or_else(|| ParseError::at(source, dimensions, "three sides in the form LxWxH"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<OrderedSides>, ParseError> {
+    let dimensions = input.lines();
+    dimensions
+        .enumerate()
+        .map(|(idx, line)| OrderedSides::from_str(line).map_err(|error| error.with_line(idx + 1)))
+        .collect()
+}
+
+fn total_wrapping_paper(all_boxes: &[OrderedSides]) -> u32 {
+    all_boxes
+        .iter()
+        .map(|ordered_sides| ordered_sides.wrapping_paper_area())
+        .sum()
+}
+
+fn total_ribbon(all_boxes: &[OrderedSides]) -> u32 {
+    all_boxes
+        .iter()
+        .map(|ordered_sides| ordered_sides.ribbon_length())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn examples() -> Vec<OrderedSides> {
+        vec![OrderedSides::new(2, 3, 4), OrderedSides::new(1, 1, 10)]
+    }
+
+    #[test]
+    fn parse_box_dimensions_examples() {
+        let examples = examples();
+        assert_eq!(OrderedSides::from_str("2x3x4").unwrap(), examples[0]);
+        assert_eq!(OrderedSides::from_str("1x1x10").unwrap(), examples[1]);
+    }
+
+    #[test]
+    fn part_1_examples() {
+        assert_eq!(total_wrapping_paper(&examples()), 58 + 43);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        assert_eq!(total_ribbon(&examples()), 34 + 14);
+    }
+
+    #[test]
+    fn get_wrapping_paper_area_examples() {
+        let examples = examples();
+        assert_eq!(examples[0].wrapping_paper_area(), 58);
+        assert_eq!(examples[1].wrapping_paper_area(), 43);
+    }
+
+    #[test]
+    fn get_ribbon_length_examples() {
+        let examples = examples();
+        assert_eq!(examples[0].ribbon_length(), 34);
+        assert_eq!(examples[1].ribbon_length(), 14);
+    }
+}
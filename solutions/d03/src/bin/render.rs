@@ -0,0 +1,60 @@
+use std::{
+    env,
+    fs::File,
+    io::{BufWriter, Write},
+    process::ExitCode,
+};
+
+use aoc_core::{InputSource, Solution};
+use d03::{render::Renderer, Day03, Simulation};
+
+const USAGE: &str = "Usage: render <OUTPUT.svg|OUTPUT.ppm|OUTPUT.png> [AGENTS] [INPUT|-]";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match render(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn render(args: &[String]) -> Result<(), String> {
+    let output = args.first().ok_or("Missing output path")?;
+    let agents = match args.get(1) {
+        Some(agents) => agents
+            .parse()
+            .map_err(|_| format!("Invalid number of agents: {agents}"))?,
+        None => 2,
+    };
+    let source = match args.get(2) {
+        Some(arg) => InputSource::from_arg(arg),
+        None => InputSource::for_day(Day03::DAY),
+    };
+    let input = source
+        .read()
+        .map_err(|error| format!("{source}: {error}"))?;
+    let instructions =
+        Day03::parse(&input).map_err(|error| error.with_file(&source.to_string()).to_string())?;
+    let simulation = Simulation::run(agents, &instructions).ok_or("Need at least one agent")?;
+
+    let renderer = Renderer::new(&simulation);
+    // Checked before creating the file, so an unknown format never truncates it.
+    let extension = output.rsplit_once('.').map(|(_, extension)| extension);
+    if !matches!(extension, Some("svg" | "ppm" | "png")) {
+        return Err(format!("Unknown image format: {output}"));
+    }
+    let written = File::create(output).and_then(|file| {
+        let mut file = BufWriter::new(file);
+        match extension {
+            Some("svg") => file.write_all(renderer.svg().as_bytes())?,
+            Some("ppm") => renderer.raster().write_ppm(&mut file)?,
+            _ => renderer.raster().write_png(&mut file)?,
+        }
+        file.flush()
+    });
+    written.map_err(|error| format!("{output}: {error}"))
+}
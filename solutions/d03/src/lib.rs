@@ -0,0 +1,291 @@
+pub mod render;
+
+use std::collections::HashMap;
+
+use aoc_core::{ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Direction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .char_indices()
+            .map(|(idx, c)| {
+                Direction::from_char(c).ok_or_else(|| {
+                    let token = &input[idx..idx + c.len_utf8()];
+                    ParseError::at(input, token, "one of '^', 'v', '<' or '>'")
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        part_2(input)
+    }
+}
+
+fn part_1(instructions: &[Direction]) -> Option<usize> {
+    Some(Simulation::run(1, instructions)?.unique_houses())
+}
+
+fn part_2(instructions: &[Direction]) -> Option<usize> {
+    Some(Simulation::run(2, instructions)?.unique_houses())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    fn step(self, (x, y): House) -> House {
+        match self {
+            Direction::Up => (x, y + 1),
+            Direction::Down => (x, y - 1),
+            Direction::Left => (x - 1, y),
+            Direction::Right => (x + 1, y),
+        }
+    }
+}
+
+pub type House = (i32, i32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HouseVisits {
+    by_agent: Vec<usize>,
+}
+
+impl HouseVisits {
+    fn new(agents: usize) -> Self {
+        Self {
+            by_agent: vec![0; agents],
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.by_agent.iter().sum()
+    }
+
+    pub fn by_agent(&self, agent: usize) -> usize {
+        self.by_agent.get(agent).copied().unwrap_or(0)
+    }
+
+    pub fn agents(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.by_agent.len()).filter(|agent| self.by_agent[*agent] > 0)
+    }
+}
+
+// Deliverers take turns following the instructions, so with two of them
+// Santa follows the even instructions and Robo-Santa the odd ones. Everyone
+// starts by delivering to the house at the origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+    paths: Vec<Vec<House>>,
+    next_agent: usize,
+    houses: HashMap<House, HouseVisits>,
+}
+
+impl Simulation {
+    pub fn new(agents: usize) -> Option<Self> {
+        if agents == 0 {
+            return None;
+        }
+        let mut origin = HouseVisits::new(agents);
+        origin.by_agent.fill(1);
+        Some(Self {
+            paths: vec![vec![(0, 0)]; agents],
+            next_agent: 0,
+            houses: HashMap::from([((0, 0), origin)]),
+        })
+    }
+
+    pub fn run(agents: usize, instructions: &[Direction]) -> Option<Self> {
+        let mut simulation = Self::new(agents)?;
+        simulation.follow(instructions);
+        Some(simulation)
+    }
+
+    pub fn follow(&mut self, instructions: &[Direction]) -> &mut Self {
+        for direction in instructions {
+            self.step(*direction);
+        }
+        self
+    }
+
+    pub fn step(&mut self, direction: Direction) {
+        let agents = self.agents();
+        let agent = self.next_agent;
+        let path = &mut self.paths[agent];
+        let house = direction.step(*path.last().expect("paths start at the origin"));
+        path.push(house);
+        self.houses
+            .entry(house)
+            .or_insert_with(|| HouseVisits::new(agents))
+            .by_agent[agent] += 1;
+        self.next_agent = (agent + 1) % agents;
+    }
+
+    pub fn agents(&self) -> usize {
+        self.paths.len()
+    }
+
+    // Every house the agent has been to, in order, starting at the origin.
+    pub fn path(&self, agent: usize) -> &[House] {
+        &self.paths[agent]
+    }
+
+    pub fn houses(&self) -> &HashMap<House, HouseVisits> {
+        &self.houses
+    }
+
+    pub fn unique_houses(&self) -> usize {
+        self.houses.len()
+    }
+
+    // Ties go to the house with the lowest coordinates, so results do not
+    // depend on hash order.
+    pub fn most_visited(&self) -> (House, usize) {
+        self.houses
+            .iter()
+            .map(|(house, visits)| (*house, visits.total()))
+            .max_by(|(a, a_visits), (b, b_visits)| a_visits.cmp(b_visits).then(b.cmp(a)))
+            .expect("the origin is always visited")
+    }
+
+    // The lowest and highest corners of the smallest box holding every
+    // visited house.
+    pub fn bounding_box(&self) -> (House, House) {
+        self.houses
+            .keys()
+            .fold(((0, 0), (0, 0)), |(low, high), (x, y)| {
+                (
+                    (low.0.min(*x), low.1.min(*y)),
+                    (high.0.max(*x), high.1.max(*y)),
+                )
+            })
+    }
+
+    pub fn unique_houses_by_agent(&self) -> Vec<usize> {
+        (0..self.agents())
+            .map(|agent| {
+                self.houses
+                    .values()
+                    .filter(|visits| visits.by_agent(agent) > 0)
+                    .count()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_instructions<T>(input: T) -> Option<Vec<Direction>>
+    where
+        T: Iterator<Item = char>,
+    {
+        input.map(Direction::from_char).collect()
+    }
+
+    fn examples() -> Vec<String> {
+        vec!["^v".to_owned(), "^>v<".to_owned(), "^v^v^v^v^v".to_owned()]
+    }
+
+    #[test]
+    fn follow_instructions_examples() {
+        let examples = examples();
+        let results = examples
+            .iter()
+            .map(|example| {
+                let instructions = get_instructions(example.chars()).unwrap();
+                let mut simulation = Simulation::new(1).unwrap();
+                simulation.follow(&instructions).unique_houses()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![2, 4, 2]);
+    }
+
+    #[test]
+    fn part_1_examples() {
+        let examples = examples();
+        let results = examples
+            .iter()
+            .map(|example| part_1(&get_instructions(example.chars()).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![2, 4, 2]);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = examples();
+        let results = examples
+            .iter()
+            .map(|example| part_2(&get_instructions(example.chars()).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![3, 3, 11]);
+    }
+
+    #[test]
+    fn visit_counts() {
+        let instructions = get_instructions("^v^v^v^v^v".chars()).unwrap();
+        let simulation = Simulation::run(1, &instructions).unwrap();
+        let origin = &simulation.houses()[&(0, 0)];
+        assert_eq!(origin.total(), 6);
+        assert_eq!(simulation.most_visited(), ((0, 0), 6));
+
+        let simulation = Simulation::run(2, &instructions).unwrap();
+        assert_eq!(
+            simulation.houses()[&(0, 0)].agents().collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            simulation.houses()[&(0, 5)].agents().collect::<Vec<_>>(),
+            vec![0]
+        );
+        assert_eq!(simulation.houses()[&(0, -5)].by_agent(1), 1);
+        assert_eq!(simulation.most_visited(), ((0, 0), 2));
+    }
+
+    #[test]
+    fn statistics() {
+        let instructions = get_instructions("^>v<<<vv>".chars()).unwrap();
+        let simulation = Simulation::run(3, &instructions).unwrap();
+        // Agent 0 follows ^, <, v; agent 1 >, <, v; agent 2 v, <, >.
+        assert_eq!(simulation.bounding_box(), ((-1, -1), (1, 1)));
+        assert_eq!(simulation.unique_houses_by_agent(), vec![4, 3, 3]);
+        assert_eq!(simulation.unique_houses(), 7);
+        assert!(Simulation::new(0).is_none());
+    }
+
+    #[test]
+    fn round_robin_continues_across_calls() {
+        let instructions = get_instructions("^>v<".chars()).unwrap();
+        let mut split = Simulation::new(3).unwrap();
+        split.follow(&instructions[..1]).follow(&instructions[1..]);
+        assert_eq!(split, Simulation::run(3, &instructions).unwrap());
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use super::{House, Simulation};
+
+// Cycled through when there are more agents than colours.
+const PALETTE: [[u8; 3]; 6] = [
+    [214, 39, 40],
+    [31, 119, 180],
+    [44, 160, 44],
+    [255, 127, 14],
+    [148, 103, 189],
+    [23, 190, 207],
+];
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+// Even a single visit should stand out from the background.
+const MIN_INTENSITY: f64 = 0.25;
+
+pub struct Renderer<'a> {
+    simulation: &'a Simulation,
+    // Scanning every house for it is costly, so it is only done once.
+    bounding_box: (House, House),
+    scale: u32,
+    paths: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(simulation: &'a Simulation) -> Self {
+        Self {
+            simulation,
+            bounding_box: simulation.bounding_box(),
+            scale: 4,
+            paths: true,
+        }
+    }
+
+    // Pixels per house.
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn with_paths(mut self, paths: bool) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn grid_size(&self) -> (u32, u32) {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounding_box;
+        ((max_x - min_x + 1) as u32, (max_y - min_y + 1) as u32)
+    }
+
+    // Grid cell of a house, with north at the top.
+    fn cell(&self, (x, y): House) -> (u32, u32) {
+        let ((min_x, _), (_, max_y)) = self.bounding_box;
+        ((x - min_x) as u32, (max_y - y) as u32)
+    }
+
+    // Each agent's colour, weighted by how often they visited, faded towards
+    // the background for houses visited less than the busiest one.
+    fn house_colour(&self, house: House, busiest: usize) -> [u8; 3] {
+        let visits = &self.simulation.houses()[&house];
+        let total = visits.total();
+        let mut colour = [0.0; 3];
+        for agent in visits.agents() {
+            let weight = visits.by_agent(agent) as f64 / total as f64;
+            for (channel, value) in colour.iter_mut().zip(agent_colour(agent)) {
+                *channel += weight * f64::from(value);
+            }
+        }
+        let intensity = MIN_INTENSITY + (1.0 - MIN_INTENSITY) * total as f64 / busiest as f64;
+        let mut blended = BACKGROUND;
+        for (channel, value) in blended.iter_mut().zip(colour) {
+            *channel = (f64::from(*channel) * (1.0 - intensity) + value * intensity).round() as u8;
+        }
+        blended
+    }
+
+    pub fn svg(&self) -> String {
+        let (columns, rows) = self.grid_size();
+        let (_, busiest) = self.simulation.most_visited();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {columns} {rows}\">\n",
+            columns * self.scale,
+            rows * self.scale,
+        );
+        writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+            hex(BACKGROUND)
+        )
+        .unwrap();
+        for house in sorted_houses(self.simulation) {
+            let (column, row) = self.cell(house);
+            let colour = hex(self.house_colour(house, busiest));
+            writeln!(
+                svg,
+                "<rect x=\"{column}\" y=\"{row}\" width=\"1\" height=\"1\" fill=\"{colour}\"/>"
+            )
+            .unwrap();
+        }
+        if self.paths {
+            for agent in 0..self.simulation.agents() {
+                let points = self
+                    .simulation
+                    .path(agent)
+                    .iter()
+                    .map(|house| {
+                        let (column, row) = self.cell(*house);
+                        format!("{}.5,{}.5", column, row)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                writeln!(
+                    svg,
+                    "<polyline points=\"{points}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.15\" stroke-opacity=\"0.8\"/>",
+                    hex(agent_colour(agent))
+                )
+                .unwrap();
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn raster(&self) -> Image {
+        let (columns, rows) = self.grid_size();
+        let (_, busiest) = self.simulation.most_visited();
+        let mut image = Image::new(columns * self.scale, rows * self.scale);
+        for house in sorted_houses(self.simulation) {
+            let (column, row) = self.cell(house);
+            let colour = self.house_colour(house, busiest);
+            for dy in 0..self.scale {
+                for dx in 0..self.scale {
+                    image.set(column * self.scale + dx, row * self.scale + dy, colour);
+                }
+            }
+        }
+        // Paths run through the middle of each house, so only show up when
+        // there is room for them next to the house colour.
+        if self.paths && self.scale >= 3 {
+            let middle = self.scale / 2;
+            for agent in 0..self.simulation.agents() {
+                let colour = agent_colour(agent);
+                for step in self.simulation.path(agent).windows(2) {
+                    let (from, to) = (self.cell(step[0]), self.cell(step[1]));
+                    let (x0, x1) = (from.0.min(to.0), from.0.max(to.0));
+                    let (y0, y1) = (from.1.min(to.1), from.1.max(to.1));
+                    for x in x0 * self.scale + middle..=x1 * self.scale + middle {
+                        for y in y0 * self.scale + middle..=y1 * self.scale + middle {
+                            image.set(x, y, colour);
+                        }
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    // Row-major RGB triples.
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    fn new(width: u32, height: u32) -> Self {
+        let pixels = BACKGROUND.repeat((width * height) as usize);
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 3] {
+        let idx = ((y * self.width + x) * 3) as usize;
+        [self.pixels[idx], self.pixels[idx + 1], self.pixels[idx + 2]]
+    }
+
+    fn set(&mut self, x: u32, y: u32, colour: [u8; 3]) {
+        let idx = ((y * self.width + x) * 3) as usize;
+        self.pixels[idx..idx + 3].copy_from_slice(&colour);
+    }
+
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(io::Error::other)
+    }
+}
+
+fn agent_colour(agent: usize) -> [u8; 3] {
+    PALETTE[agent % PALETTE.len()]
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+// Keeps the output the same from run to run, whatever the hash order.
+fn sorted_houses(simulation: &Simulation) -> Vec<House> {
+    let mut houses = simulation.houses().keys().copied().collect::<Vec<_>>();
+    houses.sort_unstable();
+    houses
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Direction::{Down, Right, Up};
+
+    // Santa goes up and right, Robo-Santa goes down twice.
+    fn example() -> Simulation {
+        Simulation::run(2, &[Up, Down, Right, Down]).unwrap()
+    }
+
+    #[test]
+    fn house_colours() {
+        let simulation = example();
+        let renderer = Renderer::new(&simulation);
+        // The origin is shared equally, everywhere else is visited once.
+        assert_eq!(renderer.house_colour((0, 0), 2), [123, 79, 110]);
+        assert_eq!(renderer.house_colour((1, 1), 2), [229, 120, 121]);
+        assert_eq!(renderer.house_colour((0, -2), 2), [115, 170, 208]);
+    }
+
+    #[test]
+    fn svg_examples() {
+        let simulation = example();
+        let svg = Renderer::new(&simulation).with_scale(10).svg();
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"40\" viewBox=\"0 0 2 4\">"
+        ));
+        assert_eq!(svg.matches("<rect x=").count(), 5);
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"1\" height=\"1\" fill=\"#7b4f6e\"/>"));
+        assert!(svg.contains("<polyline points=\"0.5,1.5 0.5,0.5 1.5,0.5\""));
+        assert!(svg.contains("<polyline points=\"0.5,1.5 0.5,2.5 0.5,3.5\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+
+        let svg = Renderer::new(&simulation).with_paths(false).svg();
+        assert!(!svg.contains("polyline"));
+    }
+
+    #[test]
+    fn raster_examples() {
+        let simulation = example();
+        let image = Renderer::new(&simulation).with_scale(3).raster();
+        assert_eq!((image.width(), image.height()), (6, 12));
+        // The corner of the origin's cell, then the path through its middle.
+        assert_eq!(image.pixel(0, 3), [123, 79, 110]);
+        assert_eq!(image.pixel(1, 4), agent_colour(1));
+        // Nobody visits (1, -1).
+        assert_eq!(image.pixel(4, 7), BACKGROUND);
+    }
+
+    #[test]
+    fn image_formats() {
+        let simulation = example();
+        let image = Renderer::new(&simulation).with_scale(1).raster();
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n2 4\n255\n"));
+        assert_eq!(ppm.len(), b"P6\n2 4\n255\n".len() + 2 * 4 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let decoder = png::Decoder::new(&png[..]);
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, image.pixels);
+    }
+}
@@ -0,0 +1,50 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use d04::{
+    hash::{Hasher, Md5, Sha1, Sha256, StandaloneMd5},
+    Difficulty, Miner,
+};
+
+// Short enough to sample quickly, long enough that the search dominates.
+const KEY: &str = "abcdef";
+const DIFFICULTY: u32 = 16;
+
+fn hash_one<H: Hasher>(data: &[u8]) -> H::Digest {
+    let mut hasher = H::new();
+    hasher.update(data);
+    hasher.finish()
+}
+
+fn hashers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hash");
+    group.bench_function("md5", |b| {
+        b.iter(|| hash_one::<Md5>(black_box(b"abcdef609043")))
+    });
+    group.bench_function("standalone_md5", |b| {
+        b.iter(|| hash_one::<StandaloneMd5>(black_box(b"abcdef609043")))
+    });
+    group.bench_function("sha1", |b| {
+        b.iter(|| hash_one::<Sha1>(black_box(b"abcdef609043")))
+    });
+    group.bench_function("sha256", |b| {
+        b.iter(|| hash_one::<Sha256>(black_box(b"abcdef609043")))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("mine");
+    group.sample_size(10);
+    let difficulty = Difficulty::bits(DIFFICULTY);
+    group.bench_function("md5", |b| {
+        let miner = Miner::new(KEY.to_owned());
+        b.iter(|| miner.mine(difficulty))
+    });
+    group.bench_function("standalone_md5", |b| {
+        let miner = Miner::<StandaloneMd5>::with_hasher(KEY.to_owned());
+        b.iter(|| miner.mine(difficulty))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, hashers);
+criterion_main!(benches);
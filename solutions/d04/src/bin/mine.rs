@@ -0,0 +1,50 @@
+use std::{env, num::NonZeroUsize, path::Path, process::ExitCode, thread};
+
+use aoc_core::{InputSource, Solution};
+use d04::{Day04, Difficulty};
+
+const USAGE: &str = "Usage: mine [--checkpoint PATH] [ZERO_BITS] [INPUT|-]";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match mine(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn mine(args: &[String]) -> Result<(), String> {
+    let (checkpoint, args) = match args {
+        [flag, path, rest @ ..] if flag == "--checkpoint" => (Some(Path::new(path)), rest),
+        rest => (None, rest),
+    };
+    let bits = match args.first() {
+        Some(bits) => bits
+            .parse()
+            .map_err(|_| format!("Invalid number of zero bits: {bits}"))?,
+        None => 24,
+    };
+    let source = match args.get(1) {
+        Some(arg) => InputSource::from_arg(arg),
+        None => InputSource::for_day(Day04::DAY),
+    };
+    let input = source
+        .read()
+        .map_err(|error| format!("{source}: {error}"))?;
+    let miner = Day04::parse(&input).map_err(|error| format!("{source}: {error}"))?;
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let found = miner
+        .mine_resumable(Difficulty::bits(bits), workers, checkpoint, |progress| {
+            eprintln!("{progress}")
+        })
+        .map_err(|error| format!("Could not use checkpoint: {error}"))?;
+    match found {
+        Some(suffix) => println!("{suffix} produces a hash that starts with {bits} zero bits"),
+        None => println!("No suffix produces a hash that starts with {bits} zero bits"),
+    }
+    Ok(())
+}
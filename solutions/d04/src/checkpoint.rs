@@ -0,0 +1,162 @@
+use std::{fmt, fs, io, path::Path, time::Duration};
+
+use crate::Difficulty;
+
+// Where a search got to, saved as one `name value` pair per line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub secret_key: String,
+    pub hasher: String,
+    pub difficulty: Difficulty,
+    // Wider than a suffix so a search that ran off the end can say so.
+    pub next_suffix: u64,
+}
+
+impl Checkpoint {
+    // A missing file just means there is nothing to resume.
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Checkpoint::parse(&contents).map(Some).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is not a valid checkpoint", path.display()),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Option<Self> {
+        let mut lines = contents.lines().map(|line| line.split_once(' '));
+        let mut field = |name| match lines.next()? {
+            Some((field, value)) if field == name => Some(value),
+            _ => None,
+        };
+        Some(Checkpoint {
+            secret_key: field("key")?.to_owned(),
+            hasher: field("hasher")?.to_owned(),
+            difficulty: Difficulty::bits(field("difficulty")?.parse().ok()?),
+            next_suffix: field("next")?.parse().ok()?,
+        })
+    }
+
+    // Written to a temporary file first, so being interrupted mid-save leaves
+    // the previous checkpoint intact.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let partial = path.with_extension("part");
+        fs::write(&partial, self.to_string())?;
+        fs::rename(&partial, path)
+    }
+}
+
+impl fmt::Display for Checkpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "key {}", self.secret_key)?;
+        writeln!(f, "hasher {}", self.hasher)?;
+        writeln!(f, "difficulty {}", self.difficulty.leading_zero_bits())?;
+        writeln!(f, "next {}", self.next_suffix)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    pub difficulty: Difficulty,
+    pub next_suffix: u64,
+    // Counted from when this run started, not from any checkpoint.
+    pub tried: u64,
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    pub fn hash_rate(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.tried as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+
+    // Every hash meets the difficulty with the same chance, 2^-bits, however
+    // many have failed before it, so the expected wait never shrinks.
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.hash_rate();
+        let expected = 2f64.powi(self.difficulty.leading_zero_bits() as i32);
+        (rate > 0.0).then(|| Duration::from_secs_f64(expected / rate))
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tried {} suffixes, up to {}, at {:.2} MH/s",
+            self.tried,
+            self.next_suffix,
+            self.hash_rate() / 1e6
+        )?;
+        match self.eta() {
+            Some(eta) => write!(f, ", expect to finish in about {}s", eta.as_secs()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Checkpoint {
+        Checkpoint {
+            secret_key: "abc def".to_owned(),
+            hasher: "md5".to_owned(),
+            difficulty: Difficulty::bits(22),
+            next_suffix: 4194305,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let contents = example().to_string();
+        assert_eq!(
+            contents,
+            "key abc def\nhasher md5\ndifficulty 22\nnext 4194305\n"
+        );
+        assert_eq!(Checkpoint::parse(&contents), Some(example()));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Checkpoint::parse(""), None);
+        assert_eq!(
+            Checkpoint::parse("hasher md5\nkey abc\ndifficulty 1\nnext 2"),
+            None
+        );
+        assert_eq!(
+            Checkpoint::parse("key abc\nhasher md5\ndifficulty x\nnext 2"),
+            None
+        );
+    }
+
+    #[test]
+    fn progress_examples() {
+        let progress = Progress {
+            difficulty: Difficulty::bits(20),
+            next_suffix: 2000001,
+            tried: 2000000,
+            elapsed: Duration::from_secs(2),
+        };
+        assert_eq!(progress.hash_rate(), 1e6);
+        assert_eq!(progress.eta(), Some(Duration::from_secs_f64(1.048576)));
+        assert_eq!(
+            progress.to_string(),
+            "tried 2000000 suffixes, up to 2000001, at 1.00 MH/s, expect to finish in about 1s"
+        );
+        let stalled = Progress {
+            elapsed: Duration::ZERO,
+            ..progress
+        };
+        assert_eq!(stalled.eta(), None);
+    }
+}
@@ -0,0 +1,237 @@
+use sha1::Digest as _;
+
+// An incremental hash the miner can run its search with. States are cloned
+// after absorbing the secret key, so only the suffix is hashed per attempt.
+pub trait Hasher: Clone + Sync {
+    type Digest: AsRef<[u8]>;
+
+    // Identifies the algorithm in checkpoints, so a search is never resumed
+    // with a different one.
+    const NAME: &'static str;
+
+    fn new() -> Self;
+    fn update(&mut self, data: &[u8]);
+    fn finish(self) -> Self::Digest;
+}
+
+// MD5 from the `md5` crate, as used by the puzzle.
+#[derive(Clone)]
+pub struct Md5(md5::Context);
+
+impl Hasher for Md5 {
+    type Digest = [u8; 16];
+    const NAME: &'static str = "md5";
+
+    fn new() -> Self {
+        Md5(md5::Context::new())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.consume(data);
+    }
+
+    fn finish(self) -> Self::Digest {
+        self.0.compute().0
+    }
+}
+
+#[derive(Clone)]
+pub struct Sha1(sha1::Sha1);
+
+impl Hasher for Sha1 {
+    type Digest = [u8; 20];
+    const NAME: &'static str = "sha1";
+
+    fn new() -> Self {
+        Sha1(sha1::Sha1::new())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finish(self) -> Self::Digest {
+        self.0.finalize().into()
+    }
+}
+
+#[derive(Clone)]
+pub struct Sha256(sha2::Sha256);
+
+impl Hasher for Sha256 {
+    type Digest = [u8; 32];
+    const NAME: &'static str = "sha256";
+
+    fn new() -> Self {
+        Sha256(sha2::Sha256::new())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finish(self) -> Self::Digest {
+        self.0.finalize().into()
+    }
+}
+
+// Per-round shift amounts and the integer parts of abs(sin(i + 1)) * 2^32,
+// straight from RFC 1321.
+const SHIFTS: [u32; 16] = [7, 12, 17, 22, 5, 9, 14, 20, 4, 11, 16, 23, 6, 10, 15, 21];
+const SINES: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+// A self-contained MD5, kept alongside the `md5` crate so the two can be
+// checked and benchmarked against each other.
+#[derive(Clone)]
+pub struct StandaloneMd5 {
+    state: [u32; 4],
+    block: [u8; 64],
+    block_len: usize,
+    total_len: u64,
+}
+
+impl StandaloneMd5 {
+    fn compress(&mut self) {
+        let words: [u32; 16] = std::array::from_fn(|i| {
+            u32::from_le_bytes(self.block[i * 4..][..4].try_into().unwrap())
+        });
+        let [mut a, mut b, mut c, mut d] = self.state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(SINES[i])
+                .wrapping_add(words[g])
+                .rotate_left(SHIFTS[i / 16 * 4 + i % 4]);
+            (a, b, c, d) = (d, b.wrapping_add(rotated), b, c);
+        }
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+impl Hasher for StandaloneMd5 {
+    type Digest = [u8; 16];
+    // The same hashes as the crate, so checkpoints can be shared.
+    const NAME: &'static str = "md5";
+
+    fn new() -> Self {
+        StandaloneMd5 {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            block: [0; 64],
+            block_len: 0,
+            total_len: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let taken = data.len().min(64 - self.block_len);
+            self.block[self.block_len..][..taken].copy_from_slice(&data[..taken]);
+            self.block_len += taken;
+            data = &data[taken..];
+            if self.block_len == 64 {
+                self.compress();
+                self.block_len = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Self::Digest {
+        let bit_len = self.total_len.wrapping_mul(8);
+        // Padding is a single 1 bit, then zeros up to the last 8 bytes of a
+        // block, which hold the message length in bits.
+        let padding = if self.block_len < 56 {
+            56 - self.block_len
+        } else {
+            120 - self.block_len
+        };
+        let mut tail = [0; 72];
+        tail[0] = 0x80;
+        tail[padding..][..8].copy_from_slice(&bit_len.to_le_bytes());
+        self.update(&tail[..padding + 8]);
+        let mut digest = [0; 16];
+        for (bytes, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex(digest: impl AsRef<[u8]>) -> String {
+        digest
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn hash<H: Hasher>(data: &[u8]) -> String {
+        let mut hasher = H::new();
+        hasher.update(data);
+        hex(hasher.finish())
+    }
+
+    #[test]
+    fn standalone_md5_examples() {
+        // The test suite from RFC 1321.
+        let examples = [
+            ("", "d41d8cd98f00b204e9800998ecf8427e"),
+            ("a", "0cc175b9c0f1b6a831c399e269772661"),
+            ("abc", "900150983cd24fb0d6963f7d28e17f72"),
+            ("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            (
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+        ];
+        for (data, expected) in examples {
+            assert_eq!(hash::<StandaloneMd5>(data.as_bytes()), expected);
+        }
+    }
+
+    #[test]
+    fn standalone_md5_matches_md5_crate() {
+        // Lengths around the block and padding boundaries, fed in pieces.
+        let data = (0..=255).cycle().take(300).collect::<Vec<u8>>();
+        for len in 0..data.len() {
+            let mut standalone = StandaloneMd5::new();
+            for piece in data[..len].chunks(7) {
+                standalone.update(piece);
+            }
+            assert_eq!(hex(standalone.finish()), hash::<Md5>(&data[..len]), "{len}");
+        }
+    }
+
+    #[test]
+    fn sha_examples() {
+        assert_eq!(
+            hash::<Sha1>(b"abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hash::<Sha256>(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
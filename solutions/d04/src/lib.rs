@@ -0,0 +1,446 @@
+pub mod checkpoint;
+pub mod hash;
+
+use std::{
+    io,
+    num::NonZeroUsize,
+    ops::RangeInclusive,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::Instant,
+};
+
+use aoc_core::{ParseError, Solution};
+
+use checkpoint::{Checkpoint, Progress};
+use hash::{Hasher, Md5};
+
+// Suffixes a worker claims at a time: large enough to keep contention on the
+// shared counter low, small enough that little work is wasted past the answer.
+const CHUNK_SIZE: u64 = 4096;
+
+// Suffixes searched between progress reports and checkpoints by default,
+// around a second's work for a single core.
+const PROGRESS_INTERVAL: u64 = 1 << 22;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Miner;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Miner::new(input.trim().to_owned()))
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        input.mine_parallel(Difficulty::hex_zeros(5), available_workers())
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        input.mine_parallel(Difficulty::hex_zeros(6), available_workers())
+    }
+}
+
+// How many leading zero bits a hash needs, so difficulties between whole hex
+// digits can be mined too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Difficulty {
+    bits: u32,
+}
+
+impl Difficulty {
+    pub fn bits(bits: u32) -> Self {
+        Difficulty { bits }
+    }
+
+    pub fn hex_zeros(zeros: u32) -> Self {
+        Difficulty { bits: zeros * 4 }
+    }
+
+    pub fn leading_zero_bits(&self) -> u32 {
+        self.bits
+    }
+
+    fn is_met_by(&self, digest: &[u8]) -> bool {
+        let mut zeros = 0;
+        for byte in digest {
+            zeros += byte.leading_zeros();
+            if zeros >= self.bits || *byte != 0 {
+                break;
+            }
+        }
+        zeros >= self.bits
+    }
+}
+
+pub struct Miner<H = Md5> {
+    // The secret key is the same for every attempt, so it is hashed once and
+    // each attempt only feeds its suffix into a copy of this state.
+    key_state: H,
+    secret_key: String,
+    suffixes: RangeInclusive<u32>,
+    progress_interval: u64,
+}
+
+impl Miner {
+    pub fn new(secret_key: String) -> Self {
+        Miner::with_hasher(secret_key)
+    }
+}
+
+impl<H: Hasher> Miner<H> {
+    pub fn with_hasher(secret_key: String) -> Self {
+        let mut key_state = H::new();
+        key_state.update(secret_key.as_bytes());
+        Miner {
+            key_state,
+            secret_key,
+            suffixes: 1..=u32::MAX,
+            progress_interval: PROGRESS_INTERVAL,
+        }
+    }
+
+    pub fn with_suffixes(mut self, suffixes: RangeInclusive<u32>) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    pub fn with_progress_interval(mut self, suffixes: u32) -> Self {
+        self.progress_interval = suffixes.max(1).into();
+        self
+    }
+
+    pub fn mine(&self, difficulty: Difficulty) -> Option<u32> {
+        self.suffixes
+            .clone()
+            .find(|suffix| difficulty.is_met_by(self.digest(*suffix).as_ref()))
+    }
+
+    pub fn mine_parallel(&self, difficulty: Difficulty, workers: usize) -> Option<u32> {
+        let (first, last) = (*self.suffixes.start(), *self.suffixes.end());
+        self.search(first.into(), last.into(), difficulty, workers)
+    }
+
+    // Mines in batches, reporting progress and saving any checkpoint after
+    // each one. A matching checkpoint is picked up where it left off, and once
+    // the answer is found it is saved too, so rerunning returns it straight
+    // away.
+    pub fn mine_resumable(
+        &self,
+        difficulty: Difficulty,
+        workers: usize,
+        checkpoint: Option<&Path>,
+        mut report: impl FnMut(&Progress),
+    ) -> io::Result<Option<u32>> {
+        let mut next = u64::from(*self.suffixes.start());
+        if let Some(checkpoint) = checkpoint {
+            if let Some(saved) = Checkpoint::load(checkpoint)? {
+                if saved.secret_key != self.secret_key
+                    || saved.hasher != H::NAME
+                    || saved.difficulty != difficulty
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{} is for a different search", checkpoint.display()),
+                    ));
+                }
+                next = next.max(saved.next_suffix);
+            }
+        }
+        let last = u64::from(*self.suffixes.end());
+        let started = Instant::now();
+        let mut tried = 0;
+        while next <= last {
+            let end = (next + self.progress_interval - 1).min(last);
+            let found = self.search(next, end, difficulty, workers);
+            // The answer itself was tried too, but is where a rerun resumes.
+            let reached = found.map_or(end + 1, u64::from);
+            tried += reached - next + u64::from(found.is_some());
+            next = reached;
+            if let Some(checkpoint) = checkpoint {
+                self.checkpoint(difficulty, next).save(checkpoint)?;
+            }
+            report(&Progress {
+                difficulty,
+                next_suffix: next,
+                tried,
+                elapsed: started.elapsed(),
+            });
+            if found.is_some() {
+                return Ok(found);
+            }
+        }
+        Ok(None)
+    }
+
+    fn checkpoint(&self, difficulty: Difficulty, next_suffix: u64) -> Checkpoint {
+        Checkpoint {
+            secret_key: self.secret_key.clone(),
+            hasher: H::NAME.to_owned(),
+            difficulty,
+            next_suffix,
+        }
+    }
+
+    // Workers claim chunks of suffixes in increasing order and record the
+    // lowest qualifying suffix found so far. A worker stops once everything
+    // it could still look at is above that, so every suffix below the final
+    // answer has been checked and the result matches the serial search.
+    fn search(&self, first: u64, last: u64, difficulty: Difficulty, workers: usize) -> Option<u32> {
+        // Kept wider than a suffix so that neither can wrap around.
+        let next_chunk = AtomicU64::new(first);
+        let lowest = AtomicU64::new(u64::MAX);
+        thread::scope(|scope| {
+            for _ in 0..workers.max(1) {
+                scope.spawn(|| loop {
+                    let start = next_chunk.fetch_add(CHUNK_SIZE, Ordering::Relaxed);
+                    if start > last || start >= lowest.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    for suffix in start..(start + CHUNK_SIZE).min(last + 1) {
+                        if suffix >= lowest.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        if difficulty.is_met_by(self.digest(suffix as u32).as_ref()) {
+                            lowest.fetch_min(suffix, Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        u32::try_from(lowest.into_inner()).ok()
+    }
+
+    fn digest(&self, suffix: u32) -> H::Digest {
+        let mut digits = [0; 10];
+        let mut state = self.key_state.clone();
+        state.update(format_decimal(suffix, &mut digits));
+        state.finish()
+    }
+}
+
+// Writes `value` in decimal into the end of `buffer`, returning the digits
+// without going through a `String`.
+fn format_decimal(mut value: u32, buffer: &mut [u8; 10]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    &buffer[start..]
+}
+
+fn available_workers() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hash::{Sha1, Sha256, StandaloneMd5};
+
+    fn examples() -> Vec<String> {
+        vec!["abcdef".to_owned(), "pqrstuv".to_owned()]
+    }
+
+    #[test]
+    fn mine_examples() {
+        let examples = examples();
+        let results = examples
+            .iter()
+            .map(|example| Miner::new(example.into()))
+            .map(|m| m.mine(Difficulty::hex_zeros(5)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![609043, 1048970])
+    }
+
+    #[test]
+    fn digest_examples() {
+        let examples = examples();
+        let results = examples
+            .iter()
+            .map(|example| Miner::new(example.into()))
+            .map(|m| {
+                m.digest(1)
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                "5f8b62a2dced0cd28946a9c891ff3e5e",
+                "be2406f4b525648848bb8c9efa215717"
+            ]
+        );
+    }
+
+    #[test]
+    fn mine_parallel_examples() {
+        let examples = examples();
+        for workers in [1, 2, 3, 8] {
+            let results = examples
+                .iter()
+                .map(|example| Miner::new(example.into()))
+                .map(|m| m.mine_parallel(Difficulty::hex_zeros(5), workers).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(results, vec![609043, 1048970]);
+        }
+    }
+
+    #[test]
+    fn mine_parallel_matches_serial() {
+        // Easy difficulties have answers within the first chunk, where
+        // workers race each other the most.
+        let miner = Miner::new("abcdef".to_owned());
+        for bits in 1..=16 {
+            let difficulty = Difficulty::bits(bits);
+            assert_eq!(miner.mine_parallel(difficulty, 4), miner.mine(difficulty));
+        }
+    }
+
+    #[test]
+    fn difficulty_examples() {
+        let miner = Miner::new("abcdef".to_owned());
+        // The hash of abcdef609043 starts 000001dbbf, 23 zero bits.
+        let digest = miner.digest(609043);
+        assert!(Difficulty::hex_zeros(5).is_met_by(&digest));
+        assert!(Difficulty::bits(23).is_met_by(&digest));
+        assert!(!Difficulty::bits(24).is_met_by(&digest));
+        assert!(!Difficulty::hex_zeros(6).is_met_by(&digest));
+        assert!(Difficulty::bits(0).is_met_by(&miner.digest(1)));
+        assert_eq!(Difficulty::hex_zeros(5).leading_zero_bits(), 20);
+    }
+
+    #[test]
+    fn mine_between_hex_zeros() {
+        let miner = Miner::new("abcdef".to_owned());
+        // No lower suffix has even 20 zero bits, and 609043 has 23.
+        for bits in 21..=23 {
+            assert_eq!(miner.mine(Difficulty::bits(bits)), Some(609043));
+        }
+    }
+
+    #[test]
+    fn format_decimal_examples() {
+        let mut buffer = [0; 10];
+        assert_eq!(format_decimal(0, &mut buffer), b"0");
+        assert_eq!(format_decimal(609043, &mut buffer), b"609043");
+        assert_eq!(format_decimal(u32::MAX, &mut buffer), b"4294967295");
+    }
+
+    #[test]
+    fn suffix_range_examples() {
+        let miner = Miner::new("abcdef".to_owned());
+        let difficulty = Difficulty::hex_zeros(5);
+        assert_eq!(miner.mine(difficulty), Some(609043));
+        let miner = miner.with_suffixes(600000..=609042);
+        assert_eq!(miner.mine(difficulty), None);
+        assert_eq!(miner.mine_parallel(difficulty, 3), None);
+        let miner = miner.with_suffixes(609043..=609043);
+        assert_eq!(miner.mine_parallel(difficulty, 3), Some(609043));
+    }
+
+    #[test]
+    fn mine_with_other_hashers() {
+        let difficulty = Difficulty::bits(16);
+        let key = "abcdef".to_owned();
+        assert_eq!(Miner::new(key.clone()).mine(difficulty), Some(31556));
+        assert_eq!(
+            Miner::<StandaloneMd5>::with_hasher(key.clone()).mine(difficulty),
+            Some(31556)
+        );
+        assert_eq!(
+            Miner::<Sha1>::with_hasher(key.clone()).mine_parallel(difficulty, 3),
+            Some(185343)
+        );
+        assert_eq!(
+            Miner::<Sha256>::with_hasher(key).mine_parallel(difficulty, 3),
+            Some(71479)
+        );
+    }
+
+    fn checkpoint_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("d04-{name}-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn mine_resumable_continues_from_checkpoint() {
+        let path = checkpoint_path("resume");
+        let difficulty = Difficulty::bits(16);
+        let miner = Miner::new("abcdef".to_owned()).with_progress_interval(5000);
+
+        // Stopping short of the answer leaves a checkpoint at the end of the
+        // range searched.
+        let mut reports = Vec::new();
+        let stopped = miner.with_suffixes(1..=20000);
+        let found = stopped.mine_resumable(difficulty, 2, Some(&path), |progress| {
+            reports.push((progress.next_suffix, progress.tried))
+        });
+        assert_eq!(found.unwrap(), None);
+        assert_eq!(
+            reports,
+            vec![(5001, 5000), (10001, 10000), (15001, 15000), (20001, 20000)]
+        );
+        assert_eq!(Checkpoint::load(&path).unwrap().unwrap().next_suffix, 20001);
+
+        let mut reports = Vec::new();
+        let miner = Miner::new("abcdef".to_owned()).with_progress_interval(5000);
+        let found = miner.mine_resumable(difficulty, 2, Some(&path), |progress| {
+            reports.push((progress.next_suffix, progress.tried))
+        });
+        assert_eq!(found.unwrap(), Some(31556));
+        assert_eq!(reports, vec![(25001, 5000), (30001, 10000), (31556, 11556)]);
+
+        // The answer is checkpointed too.
+        let mut reports = Vec::new();
+        let found = miner.mine_resumable(difficulty, 2, Some(&path), |progress| {
+            reports.push((progress.next_suffix, progress.tried))
+        });
+        assert_eq!(found.unwrap(), Some(31556));
+        assert_eq!(reports, vec![(31556, 1)]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn mine_resumable_without_checkpoint() {
+        let miner = Miner::new("abcdef".to_owned()).with_progress_interval(20000);
+        let mut reports = Vec::new();
+        let found = miner.mine_resumable(Difficulty::bits(16), 2, None, |progress| {
+            reports.push((progress.next_suffix, progress.tried))
+        });
+        assert_eq!(found.unwrap(), Some(31556));
+        assert_eq!(reports, vec![(20001, 20000), (31556, 31556)]);
+    }
+
+    #[test]
+    fn mine_resumable_rejects_other_searches() {
+        let path = checkpoint_path("mismatch");
+        let miner = Miner::new("abcdef".to_owned());
+        let found = miner.mine_resumable(Difficulty::bits(8), 1, Some(&path), |_| {});
+        assert_eq!(found.unwrap(), miner.mine(Difficulty::bits(8)));
+
+        let harder = miner.mine_resumable(Difficulty::bits(9), 1, Some(&path), |_| {});
+        assert_eq!(harder.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        let other_key = Miner::new("pqrstuv".to_owned());
+        let found = other_key.mine_resumable(Difficulty::bits(8), 1, Some(&path), |_| {});
+        assert!(found.is_err());
+        let other_hasher = Miner::<Sha1>::with_hasher("abcdef".to_owned());
+        let found = other_hasher.mine_resumable(Difficulty::bits(8), 1, Some(&path), |_| {});
+        assert!(found.is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
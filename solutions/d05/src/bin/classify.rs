@@ -0,0 +1,49 @@
+use std::{env, num::NonZeroUsize, path::Path, process::ExitCode, thread};
+
+use d05::{
+    rules::{Policies, Policy},
+    stream::classify_file,
+};
+
+const USAGE: &str = "Usage: classify [--config POLICIES.toml] WORDLIST [WORKERS]";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match classify(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn classify(args: &[String]) -> Result<(), String> {
+    let (policies, args) = match args {
+        [flag, path, rest @ ..] if flag == "--config" => {
+            let policies =
+                Policies::load(Path::new(path)).map_err(|error| format!("{path}: {error}"))?;
+            (policies, rest)
+        }
+        rest => {
+            let policies = Policies {
+                policies: vec![Policy::part_1(), Policy::part_2()],
+            };
+            (policies, rest)
+        }
+    };
+    let wordlist = args.first().ok_or("Missing word list")?;
+    let workers = match args.get(1) {
+        Some(workers) => workers
+            .parse()
+            .map_err(|_| format!("Invalid number of workers: {workers}"))?,
+        None => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    };
+    for policy in &policies.policies {
+        let tally = classify_file(policy, Path::new(wordlist), workers)
+            .map_err(|error| format!("{wordlist}: {error}"))?;
+        println!("{}: {} of {} nice", policy.name, tally.nice, tally.lines);
+    }
+    Ok(())
+}
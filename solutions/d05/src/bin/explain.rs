@@ -0,0 +1,44 @@
+use std::{env, path::Path, process::ExitCode};
+
+use d05::rules::{Policies, Policy};
+
+const USAGE: &str = "Usage: explain [--config POLICIES.toml] STRING...";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match explain(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn explain(args: &[String]) -> Result<(), String> {
+    let (policies, strings) = match args {
+        [flag, path, strings @ ..] if flag == "--config" => {
+            let policies =
+                Policies::load(Path::new(path)).map_err(|error| format!("{path}: {error}"))?;
+            (policies, strings)
+        }
+        [flag] if flag == "--config" => return Err("Missing config path".to_owned()),
+        strings => {
+            let policies = Policies {
+                policies: vec![Policy::part_1(), Policy::part_2()],
+            };
+            (policies, strings)
+        }
+    };
+    if strings.is_empty() {
+        return Err("Nothing to explain".to_owned());
+    }
+    for string in strings {
+        println!("{string}");
+        for policy in &policies.policies {
+            println!("{}: {}", policy.name, policy.evaluate(string));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+pub mod rules;
+pub mod stream;
+
+use aoc_core::{ParseError, Solution};
+
+use rules::Policy;
+use stream::Classifier;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        count_nice(&Policy::part_1(), input)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        count_nice(&Policy::part_2(), input)
+    }
+}
+
+fn count_nice(policy: &Policy, input: &str) -> Option<u32> {
+    let tally = Classifier::new(policy).classify(input.as_bytes()).ok()?;
+    tally.nice.try_into().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rules::Predicate;
+
+    fn examples_p1() -> Vec<&'static str> {
+        vec![
+            "ugknbfddgicrmopn",
+            "aaa",
+            "jchzalrnumimnmhp",
+            "haegwjzuvuyypxyu",
+            "dvszwmarrgswjxmb",
+        ]
+    }
+
+    fn examples_p2() -> Vec<&'static str> {
+        vec![
+            "qjhvhtzxzqqjkmpb",
+            "xxyxx",
+            "uurcxstgmygtbstg",
+            "ieodomkazucvgmuy",
+        ]
+    }
+
+    fn passes(predicate: Predicate, examples: &[&str]) -> Vec<bool> {
+        examples
+            .iter()
+            .map(|example| predicate.check(example).0)
+            .collect()
+    }
+
+    #[test]
+    fn part_1_examples() {
+        let examples = examples_p1();
+        let result = Policy::part_1().count_nice(examples);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = examples_p2();
+        let result = Policy::part_2().count_nice(examples);
+        assert_eq!(result, 2)
+    }
+
+    #[test]
+    fn vowels_examples() {
+        let vowels = Predicate::Vowels {
+            vowels: "aeiou".to_owned(),
+            at_least: 3,
+        };
+        let results = passes(vowels, &examples_p1());
+        assert_eq!(results, vec![true, true, true, true, false])
+    }
+
+    #[test]
+    fn repeated_letter_examples_p1() {
+        let repeated = Predicate::RepeatedLetter { separation: 0 };
+        let results = passes(repeated, &examples_p1());
+        assert_eq!(results, vec![true, true, false, true, true])
+    }
+
+    #[test]
+    fn repeated_letter_examples_p2() {
+        let repeated = Predicate::RepeatedLetter { separation: 1 };
+        let results = passes(repeated, &examples_p2());
+        assert_eq!(results, vec![true, true, false, true])
+    }
+
+    #[test]
+    fn forbidden_examples() {
+        let forbidden = Predicate::Forbidden {
+            substrings: vec!["ab".into(), "cd".into(), "pq".into(), "xy".into()],
+        };
+        // Passing now means none were found.
+        let results = passes(forbidden, &examples_p1());
+        assert_eq!(results, vec![true, true, true, false, true])
+    }
+
+    #[test]
+    fn repeated_pair_examples() {
+        let results = passes(Predicate::RepeatedPair, &examples_p2());
+        assert_eq!(results, vec![true, true, true, false])
+    }
+}
@@ -0,0 +1,336 @@
+use std::{collections::HashMap, fmt, fs, io, ops::Range, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+// The predicates a rule can be built from. Positions are reported as byte
+// ranges into the string being checked.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Predicate {
+    // At least `at_least` of the letters in `vowels`.
+    Vowels {
+        #[serde(default = "default_vowels")]
+        vowels: String,
+        at_least: usize,
+    },
+    // A letter that repeats with exactly `separation` letters in between.
+    RepeatedLetter {
+        separation: usize,
+    },
+    // None of `substrings` anywhere.
+    Forbidden {
+        substrings: Vec<String>,
+    },
+    // A pair of letters that appears twice without overlapping.
+    RepeatedPair,
+}
+
+fn default_vowels() -> String {
+    "aeiou".to_owned()
+}
+
+impl Predicate {
+    // Whether `input` passes, along with the positions that decided it: what
+    // was found for rules that look for something, and what broke the rule
+    // for ones that forbid it.
+    pub fn check(&self, input: &str) -> (bool, Vec<Range<usize>>) {
+        let chars = input.char_indices().collect::<Vec<_>>();
+        let span = |from: usize, to: usize| {
+            let (start, _) = chars[from];
+            let (end, last) = chars[to];
+            start..end + last.len_utf8()
+        };
+        match self {
+            Predicate::Vowels { vowels, at_least } => {
+                let found = chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, c))| vowels.contains(*c))
+                    .map(|(idx, _)| span(idx, idx))
+                    .collect::<Vec<_>>();
+                (found.len() >= *at_least, found)
+            }
+            Predicate::RepeatedLetter { separation } => {
+                let window_size = separation + 2;
+                let found = chars
+                    .windows(window_size)
+                    .position(|window| window[0].1 == window[window_size - 1].1)
+                    .map(|idx| span(idx, idx + window_size - 1));
+                (found.is_some(), found.into_iter().collect())
+            }
+            Predicate::Forbidden { substrings } => {
+                let mut found = substrings
+                    .iter()
+                    .filter(|substring| !substring.is_empty())
+                    .flat_map(|substring| {
+                        input
+                            .match_indices(substring.as_str())
+                            .map(|(start, matched)| start..start + matched.len())
+                    })
+                    .collect::<Vec<_>>();
+                found.sort_by_key(|range| (range.start, range.end));
+                (found.is_empty(), found)
+            }
+            Predicate::RepeatedPair => {
+                let mut first_seen = HashMap::new();
+                let found = chars.windows(2).enumerate().find_map(|(idx, window)| {
+                    let pair = (window[0].1, window[1].1);
+                    match first_seen.get(&pair) {
+                        Some(prev_idx) if idx > prev_idx + 1 => {
+                            Some(vec![span(*prev_idx, prev_idx + 1), span(idx, idx + 1)])
+                        }
+                        Some(_) => None,
+                        None => {
+                            first_seen.insert(pair, idx);
+                            None
+                        }
+                    }
+                });
+                (found.is_some(), found.unwrap_or_default())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Rule {
+    pub name: String,
+    #[serde(flatten)]
+    pub predicate: Predicate,
+}
+
+impl Rule {
+    pub fn new(name: &str, predicate: Predicate) -> Self {
+        Rule {
+            name: name.to_owned(),
+            predicate,
+        }
+    }
+}
+
+// A string is nice under a policy when it passes every one of its rules.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Policy {
+    pub name: String,
+    #[serde(rename = "rule")]
+    pub rules: Vec<Rule>,
+}
+
+impl Policy {
+    pub fn part_1() -> Self {
+        Policy {
+            name: "part 1".to_owned(),
+            rules: vec![
+                Rule::new(
+                    "three vowels",
+                    Predicate::Vowels {
+                        vowels: default_vowels(),
+                        at_least: 3,
+                    },
+                ),
+                Rule::new("double letter", Predicate::RepeatedLetter { separation: 0 }),
+                Rule::new(
+                    "no forbidden pairs",
+                    Predicate::Forbidden {
+                        substrings: ["ab", "cd", "pq", "xy"].map(str::to_owned).to_vec(),
+                    },
+                ),
+            ],
+        }
+    }
+
+    pub fn part_2() -> Self {
+        Policy {
+            name: "part 2".to_owned(),
+            rules: vec![
+                Rule::new("repeated pair", Predicate::RepeatedPair),
+                Rule::new(
+                    "letter either side of another",
+                    Predicate::RepeatedLetter { separation: 1 },
+                ),
+            ],
+        }
+    }
+
+    pub fn evaluate(&self, input: &str) -> Evaluation<'_> {
+        let outcomes = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let (passed, positions) = rule.predicate.check(input);
+                Outcome {
+                    rule: &rule.name,
+                    passed,
+                    positions,
+                }
+            })
+            .collect();
+        Evaluation { outcomes }
+    }
+
+    // Skips the positions, for when only the verdict matters.
+    pub fn is_nice(&self, input: &str) -> bool {
+        self.rules.iter().all(|rule| rule.predicate.check(input).0)
+    }
+
+    pub fn count_nice<'a>(&self, lines: impl IntoIterator<Item = &'a str>) -> u32 {
+        lines.into_iter().filter(|line| self.is_nice(line)).count() as u32
+    }
+}
+
+// Policies as written in a config file, one `[[policy]]` table each with its
+// rules as `[[policy.rule]]` tables.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Policies {
+    #[serde(rename = "policy")]
+    pub policies: Vec<Policy>,
+}
+
+impl Policies {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        toml::from_str(contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Policy> {
+        self.policies.iter().find(|policy| policy.name == name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Outcome<'a> {
+    pub rule: &'a str,
+    pub passed: bool,
+    pub positions: Vec<Range<usize>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Evaluation<'a> {
+    pub outcomes: Vec<Outcome<'a>>,
+}
+
+impl Evaluation<'_> {
+    pub fn is_nice(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.passed)
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &Outcome<'_>> {
+        self.outcomes.iter().filter(|outcome| !outcome.passed)
+    }
+}
+
+// One line for the verdict, then one per rule with the positions it found.
+impl fmt::Display for Evaluation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.is_nice() { "nice" } else { "naughty" })?;
+        for outcome in &self.outcomes {
+            let verdict = if outcome.passed { "pass" } else { "fail" };
+            write!(f, "\n  {verdict} {}", outcome.rule)?;
+            for (idx, position) in outcome.positions.iter().enumerate() {
+                let separator = if idx == 0 { " at " } else { ", " };
+                if position.len() == 1 {
+                    write!(f, "{separator}{}", position.start)?;
+                } else {
+                    write!(f, "{separator}{}..{}", position.start, position.end)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn at(spans: &[(usize, usize)]) -> Vec<Range<usize>> {
+        spans.iter().map(|(start, end)| *start..*end).collect()
+    }
+
+    #[test]
+    fn predicate_positions() {
+        let vowels = Predicate::Vowels {
+            vowels: default_vowels(),
+            at_least: 3,
+        };
+        assert_eq!(
+            vowels.check("xazegov"),
+            (true, at(&[(1, 2), (3, 4), (5, 6)]))
+        );
+        assert_eq!(vowels.check("dvszwmarrgswjxmb"), (false, at(&[(6, 7)])));
+        let custom = Predicate::Vowels {
+            vowels: "y".to_owned(),
+            at_least: 2,
+        };
+        assert_eq!(custom.check("ayy"), (true, at(&[(1, 2), (2, 3)])));
+
+        let double = Predicate::RepeatedLetter { separation: 0 };
+        assert_eq!(double.check("abcdde"), (true, at(&[(3, 5)])));
+        let apart = Predicate::RepeatedLetter { separation: 1 };
+        assert_eq!(apart.check("xyx"), (true, at(&[(0, 3)])));
+        assert_eq!(apart.check("abc"), (false, vec![]));
+
+        let forbidden = Predicate::Forbidden {
+            substrings: vec!["xy".to_owned(), "ab".to_owned()],
+        };
+        assert_eq!(
+            forbidden.check("haegwjzuvuyypxyu"),
+            (false, at(&[(13, 15)]))
+        );
+        assert_eq!(
+            forbidden.check("abxyab"),
+            (false, at(&[(0, 2), (2, 4), (4, 6)]))
+        );
+
+        let pair = Predicate::RepeatedPair;
+        assert_eq!(pair.check("xyxy"), (true, at(&[(0, 2), (2, 4)])));
+        assert_eq!(pair.check("aaa"), (false, vec![]));
+    }
+
+    #[test]
+    fn evaluate_explains() {
+        let policy = Policy::part_1();
+        let evaluation = policy.evaluate("haegwjzuvuyypxyu");
+        assert!(!evaluation.is_nice());
+        let failed = evaluation
+            .failed()
+            .map(|outcome| outcome.rule)
+            .collect::<Vec<_>>();
+        assert_eq!(failed, vec!["no forbidden pairs"]);
+        assert_eq!(
+            evaluation.to_string(),
+            "naughty\n  \
+             pass three vowels at 1, 2, 7, 9, 15\n  \
+             pass double letter at 10..12\n  \
+             fail no forbidden pairs at 13..15"
+        );
+
+        let policy = Policy::part_2();
+        let evaluation = policy.evaluate("ieodomkazucvgmuy");
+        assert_eq!(
+            evaluation.to_string(),
+            "naughty\n  fail repeated pair\n  pass letter either side of another at 2..5"
+        );
+    }
+
+    #[test]
+    fn shipped_policies_match_builtin() {
+        let policies = Policies::parse(include_str!("../policies.toml")).unwrap();
+        assert_eq!(policies.get("part 1"), Some(&Policy::part_1()));
+        assert_eq!(policies.get("part 2"), Some(&Policy::part_2()));
+        let contents = toml::to_string(&policies).unwrap();
+        assert_eq!(Policies::parse(&contents).unwrap(), policies);
+    }
+
+    #[test]
+    fn parse_policies_errors() {
+        let unknown_kind = "[[policy]]\nname = \"x\"\n[[policy.rule]]\nname = \"y\"\nkind = \"z\"";
+        assert!(Policies::parse(unknown_kind).is_err());
+        let missing_count =
+            "[[policy]]\nname = \"x\"\n[[policy.rule]]\nname = \"y\"\nkind = \"vowels\"";
+        assert!(Policies::parse(missing_count).is_err());
+    }
+}
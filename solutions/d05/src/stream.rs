@@ -0,0 +1,330 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    ops::Add,
+    path::Path,
+    thread,
+};
+
+use crate::rules::{Policy, Predicate};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub lines: u64,
+    pub nice: u64,
+}
+
+impl Add for Tally {
+    type Output = Tally;
+
+    fn add(self, other: Tally) -> Tally {
+        Tally {
+            lines: self.lines + other.lines,
+            nice: self.nice + other.nice,
+        }
+    }
+}
+
+enum Check {
+    Vowels {
+        vowels: Box<[bool; 256]>,
+        at_least: usize,
+    },
+    RepeatedLetter {
+        distance: usize,
+    },
+    Forbidden {
+        substrings: Vec<Vec<u8>>,
+    },
+    RepeatedPair,
+}
+
+// Per-rule progress through the current line.
+#[derive(Clone, Copy)]
+enum State {
+    Counting(usize),
+    Passed,
+    Failed,
+    Pending,
+}
+
+// A policy compiled to work on bytes, checking every rule as it goes along a
+// line once. Rules see bytes rather than characters, so they only agree with
+// `Policy::evaluate` for ASCII rules and input.
+pub struct Classifier {
+    checks: Vec<Check>,
+    states: Vec<State>,
+    // Where each pair of bytes was first seen, tagged with the line it was
+    // seen on so the table never needs clearing between lines.
+    pairs: Vec<(u64, usize)>,
+    line: u64,
+}
+
+impl Classifier {
+    pub fn new(policy: &Policy) -> Self {
+        let checks = policy
+            .rules
+            .iter()
+            .map(|rule| match &rule.predicate {
+                Predicate::Vowels { vowels, at_least } => {
+                    let mut table = Box::new([false; 256]);
+                    for byte in vowels.bytes() {
+                        table[byte as usize] = true;
+                    }
+                    Check::Vowels {
+                        vowels: table,
+                        at_least: *at_least,
+                    }
+                }
+                Predicate::RepeatedLetter { separation } => Check::RepeatedLetter {
+                    distance: separation + 1,
+                },
+                Predicate::Forbidden { substrings } => Check::Forbidden {
+                    substrings: substrings
+                        .iter()
+                        .filter(|substring| !substring.is_empty())
+                        .map(|substring| substring.as_bytes().to_vec())
+                        .collect(),
+                },
+                Predicate::RepeatedPair => Check::RepeatedPair,
+            })
+            .collect::<Vec<_>>();
+        let uses_pairs = checks
+            .iter()
+            .any(|check| matches!(check, Check::RepeatedPair));
+        Classifier {
+            states: vec![State::Pending; checks.len()],
+            checks,
+            pairs: vec![(0, 0); if uses_pairs { 1 << 16 } else { 0 }],
+            line: 0,
+        }
+    }
+
+    pub fn is_nice(&mut self, line: &[u8]) -> bool {
+        self.line += 1;
+        for (check, state) in self.checks.iter().zip(&mut self.states) {
+            *state = match check {
+                Check::Vowels { at_least: 0, .. } | Check::Forbidden { .. } => State::Passed,
+                Check::Vowels { .. } => State::Counting(0),
+                _ => State::Pending,
+            };
+        }
+        for idx in 0..line.len() {
+            let byte = line[idx];
+            let mut failed = false;
+            for (check, state) in self.checks.iter().zip(&mut self.states) {
+                match (check, *state) {
+                    (_, State::Passed | State::Failed) => {}
+                    (Check::Vowels { vowels, at_least }, State::Counting(count))
+                        if vowels[byte as usize] =>
+                    {
+                        *state = if count + 1 >= *at_least {
+                            State::Passed
+                        } else {
+                            State::Counting(count + 1)
+                        };
+                    }
+                    (Check::RepeatedLetter { distance }, _)
+                        if idx >= *distance && line[idx - distance] == byte =>
+                    {
+                        *state = State::Passed;
+                    }
+                    (Check::RepeatedPair, _) if idx > 0 => {
+                        let pair = usize::from(line[idx - 1]) << 8 | usize::from(byte);
+                        match self.pairs[pair] {
+                            (seen_on, first) if seen_on == self.line => {
+                                if idx > first + 1 {
+                                    *state = State::Passed;
+                                }
+                            }
+                            _ => self.pairs[pair] = (self.line, idx),
+                        }
+                    }
+                    _ => {}
+                }
+                // Forbidden substrings are the only rule that can fail before
+                // the end of the line.
+                if let Check::Forbidden { substrings } = check {
+                    let seen = &line[..=idx];
+                    if substrings.iter().any(|substring| seen.ends_with(substring)) {
+                        *state = State::Failed;
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                return false;
+            }
+        }
+        self.states
+            .iter()
+            .all(|state| matches!(state, State::Passed))
+    }
+
+    // Reads line by line, so the whole input never has to be in memory.
+    pub fn classify(&mut self, mut reader: impl BufRead) -> io::Result<Tally> {
+        let mut tally = Tally::default();
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            let trimmed = line.strip_suffix(b"\n").unwrap_or(&line);
+            let trimmed = trimmed.strip_suffix(b"\r").unwrap_or(trimmed);
+            tally.lines += 1;
+            tally.nice += u64::from(self.is_nice(trimmed));
+            line.clear();
+        }
+        Ok(tally)
+    }
+}
+
+// Splits the file into one chunk per worker, each starting at a line, and
+// classifies the chunks side by side, each reading only its own part.
+pub fn classify_file(policy: &Policy, path: &Path, workers: usize) -> io::Result<Tally> {
+    let len = File::open(path)?.metadata()?.len();
+    let workers = workers.max(1) as u64;
+    let mut bounds = vec![0];
+    for worker in 1..workers {
+        let bound = line_start_after(path, len * worker / workers)?;
+        bounds.push(bound.max(*bounds.last().unwrap()));
+    }
+    bounds.push(len);
+    thread::scope(|scope| {
+        let chunks = bounds
+            .windows(2)
+            .map(|chunk| {
+                let (start, end) = (chunk[0], chunk[1]);
+                scope.spawn(move || {
+                    let mut file = File::open(path)?;
+                    file.seek(SeekFrom::Start(start))?;
+                    let reader = BufReader::new(file.take(end - start));
+                    Classifier::new(policy).classify(reader)
+                })
+            })
+            .collect::<Vec<_>>();
+        chunks
+            .into_iter()
+            .map(|chunk| chunk.join().expect("classifier thread panicked"))
+            .try_fold(Tally::default(), |total, tally| Ok(total + tally?))
+    })
+}
+
+// The first line boundary at or after `offset`.
+fn line_start_after(path: &Path, offset: u64) -> io::Result<u64> {
+    if offset == 0 {
+        return Ok(0);
+    }
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset - 1))?;
+    let mut rest_of_line = Vec::new();
+    BufReader::new(file).read_until(b'\n', &mut rest_of_line)?;
+    Ok(offset - 1 + rest_of_line.len() as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rules::Rule;
+    use std::{env, fs};
+
+    // The puzzle's examples and a few more so every rule of `policies` both
+    // passes and fails, including overlapping pairs and forbidden substrings
+    // at either end of a word.
+    fn words() -> Vec<&'static str> {
+        vec![
+            "",
+            "ugknbfddgicrmopn",
+            "aaa",
+            "jchzalrnumimnmhp",
+            "haegwjzuvuyypxyu",
+            "dvszwmarrgswjxmb",
+            "qjhvhtzxzqqjkmpb",
+            "xxyxx",
+            "uurcxstgmygtbstg",
+            "ieodomkazucvgmuy",
+            "aaaa",
+            "xyxy",
+            "xaxbxyxy",
+            "qxyyxq",
+            "abcxaxbxc",
+            "yxxyaaa",
+            "eioucdcd",
+            "xyaexyib",
+            "xyzxyz",
+            "xyzxyq",
+        ]
+    }
+
+    fn policies() -> Vec<Policy> {
+        let custom = Policy {
+            name: "custom".to_owned(),
+            rules: vec![
+                Rule::new(
+                    "two of x y",
+                    Predicate::Vowels {
+                        vowels: "xy".to_owned(),
+                        at_least: 2,
+                    },
+                ),
+                Rule::new("gap of two", Predicate::RepeatedLetter { separation: 2 }),
+                Rule::new(
+                    "no triples",
+                    Predicate::Forbidden {
+                        substrings: vec!["aaa".to_owned(), "q".to_owned()],
+                    },
+                ),
+                Rule::new("repeated pair", Predicate::RepeatedPair),
+            ],
+        };
+        vec![Policy::part_1(), Policy::part_2(), custom]
+    }
+
+    #[test]
+    fn matches_policy() {
+        let words = words();
+        for policy in policies() {
+            let mut classifier = Classifier::new(&policy);
+            for word in &words {
+                assert_eq!(
+                    classifier.is_nice(word.as_bytes()),
+                    policy.is_nice(word),
+                    "{} {word}",
+                    policy.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn words_cover_every_policy() {
+        for policy in policies() {
+            let nice = policy.count_nice(words()) as usize;
+            assert!(nice > 0 && nice < words().len(), "{}", policy.name);
+        }
+    }
+
+    #[test]
+    fn classify_examples() {
+        let input = "ugknbfddgicrmopn\r\naaa\njchzalrnumimnmhp\n\nhaegwjzuvuyypxyu";
+        let tally = Classifier::new(&Policy::part_1())
+            .classify(input.as_bytes())
+            .unwrap();
+        assert_eq!(tally, Tally { lines: 5, nice: 2 });
+    }
+
+    #[test]
+    fn classify_file_in_chunks() {
+        // Enough copies that every worker gets a chunk of several lines.
+        let words = words().repeat(25);
+        let path = env::temp_dir().join(format!("d05-words-{}.txt", std::process::id()));
+        fs::write(&path, words.join("\n")).unwrap();
+        for policy in policies() {
+            let expected = Tally {
+                lines: words.len() as u64,
+                nice: policy.count_nice(words.iter().copied()).into(),
+            };
+            for workers in [1, 2, 3, 7, 400] {
+                assert_eq!(classify_file(&policy, &path, workers).unwrap(), expected);
+            }
+        }
+        fs::remove_file(path).unwrap();
+    }
+}
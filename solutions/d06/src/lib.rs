@@ -0,0 +1,330 @@
+use std::{convert, ops::Range};
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+const GRID_SIZE: usize = 1000;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(total_lit(GRID_SIZE, input))
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(total_brightness(GRID_SIZE, input))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let instruction = line.split(&[' ', ',']).collect::<Vec<_>>();
+            let (action, area) = match instruction.as_slice() {
+                ["turn", "on", rest @ ..] => (Action::On, rest),
+                ["turn", "off", rest @ ..] => (Action::Off, rest),
+                ["toggle", rest @ ..] => (Action::Toggle, rest),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "'turn on', 'turn off' or 'toggle'",
+                    ))
+                }
+            };
+            let rectangle = match area[..] {
+                [x1, y1, "through", x2, y2] => Rectangle::from_str(input, x1, y1, x2, y2)?,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "an area in the form 'x1,y1 through x2,y2'",
+                    ))
+                }
+            };
+            Ok(Instruction::new(action, rectangle))
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    action: Action,
+    rectangle: Rectangle,
+}
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug)]
+struct Rectangle {
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+}
+
+impl Instruction {
+    fn new(action: Action, rectangle: Rectangle) -> Instruction {
+        Instruction { action, rectangle }
+    }
+}
+
+impl Action {
+    fn switch(&self, on: bool) -> bool {
+        match self {
+            Action::On => true,
+            Action::Off => false,
+            Action::Toggle => !on,
+        }
+    }
+
+    fn adjust(&self, brightness: usize) -> usize {
+        match self {
+            Action::On => brightness + 1,
+            Action::Toggle => brightness + 2,
+            Action::Off => brightness.saturating_sub(1),
+        }
+    }
+}
+
+impl Rectangle {
+    fn new(x1: usize, y1: usize, x2: usize, y2: usize) -> Rectangle {
+        Rectangle { x1, y1, x2, y2 }
+    }
+
+    fn from_str(
+        input: &str,
+        x1: &str,
+        y1: &str,
+        x2: &str,
+        y2: &str,
+    ) -> Result<Rectangle, ParseError> {
+        let x1 = parse_token(input, x1, "a coordinate")?;
+        let y1 = parse_token(input, y1, "a coordinate")?;
+        let x2 = parse_token(input, x2, "a coordinate")?;
+        let y2 = parse_token(input, y2, "a coordinate")?;
+        Ok(Rectangle::new(x1, y1, x2, y2))
+    }
+}
+
+fn total_lit(grid_size: usize, instructions: &[Instruction]) -> usize {
+    sweep(grid_size, instructions, false, Action::switch, usize::from)
+}
+
+fn total_brightness(grid_size: usize, instructions: &[Instruction]) -> usize {
+    sweep(
+        grid_size,
+        instructions,
+        0,
+        Action::adjust,
+        convert::identity,
+    )
+}
+
+// Cuts the grid along every rectangle edge, so that every light within a
+// region is always affected by the same instructions. Each instruction is then
+// applied once per region it covers, and each region counts for its area.
+fn sweep<T: Copy>(
+    grid_size: usize,
+    instructions: &[Instruction],
+    initial: T,
+    apply: fn(&Action, T) -> T,
+    value: fn(T) -> usize,
+) -> usize {
+    let rectangles = instructions
+        .iter()
+        .map(|instruction| &instruction.rectangle);
+    let xs = boundaries(grid_size, rectangles.clone().map(|r| (r.x1, r.x2)));
+    let ys = boundaries(grid_size, rectangles.map(|r| (r.y1, r.y2)));
+    let columns = xs.len() - 1;
+    let mut regions = vec![initial; columns * (ys.len() - 1)];
+    for instruction in instructions {
+        let rectangle = &instruction.rectangle;
+        let covered_columns = covered(&xs, rectangle.x1, rectangle.x2);
+        for row in covered(&ys, rectangle.y1, rectangle.y2) {
+            for region in &mut regions[row * columns..][covered_columns.clone()] {
+                *region = apply(&instruction.action, *region);
+            }
+        }
+    }
+    regions
+        .iter()
+        .enumerate()
+        .map(|(idx, region)| {
+            let (row, column) = (idx / columns, idx % columns);
+            let area = (xs[column + 1] - xs[column]) * (ys[row + 1] - ys[row]);
+            value(*region) * area
+        })
+        .sum()
+}
+
+// The sorted, distinct coordinates where regions start along one axis, ending
+// with the edge of the grid. Inclusive ranges become half-open ones here.
+fn boundaries(grid_size: usize, ranges: impl Iterator<Item = (usize, usize)>) -> Vec<usize> {
+    let mut bounds = vec![0, grid_size];
+    for (from, to) in ranges {
+        bounds.push(from.min(grid_size));
+        bounds.push(to.saturating_add(1).min(grid_size));
+    }
+    bounds.sort_unstable();
+    bounds.dedup();
+    bounds
+}
+
+// The regions between `from` and `to` inclusive, by index into `bounds`.
+// Anything past the last bound is off the grid, so covers no region.
+fn covered(bounds: &[usize], from: usize, to: usize) -> Range<usize> {
+    let regions = bounds.len() - 1;
+    let start = bounds.partition_point(|bound| *bound < from).min(regions);
+    let end = bounds.partition_point(|bound| *bound <= to).min(regions);
+    start..end.max(start)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Follow a single light through every instruction, to check the sweep.
+    fn light_is_on(x: usize, y: usize, instructions: &[Instruction]) -> bool {
+        instructions
+            .iter()
+            .filter(|instruction| instruction.rectangle.contains(x, y))
+            .fold(false, |on, instruction| instruction.action.switch(on))
+    }
+
+    fn calculate_brightness(x: usize, y: usize, instructions: &[Instruction]) -> usize {
+        instructions
+            .iter()
+            .filter(|instruction| instruction.rectangle.contains(x, y))
+            .fold(0, |brightness, instruction| {
+                instruction.action.adjust(brightness)
+            })
+    }
+
+    impl Rectangle {
+        fn contains(&self, x: usize, y: usize) -> bool {
+            x >= self.x1 && x <= self.x2 && y >= self.y1 && y <= self.y2
+        }
+    }
+
+    fn examples() -> Vec<Instruction> {
+        vec![
+            Instruction::new(Action::On, Rectangle::new(0, 0, 9, 9)),
+            Instruction::new(Action::Toggle, Rectangle::new(0, 0, 9, 0)),
+            Instruction::new(Action::Off, Rectangle::new(4, 4, 5, 5)),
+            Instruction::new(Action::On, Rectangle::new(4, 4, 5, 5)),
+        ]
+    }
+
+    #[test]
+    fn part_1_examples() {
+        let examples = examples();
+        assert_eq!(total_lit(10, &examples), 90);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let examples = examples();
+        assert_eq!(total_brightness(10, &examples), 120);
+    }
+
+    #[test]
+    fn light_is_on_examples() {
+        let examples = examples();
+        assert!(!light_is_on(0, 0, &examples));
+        assert!(light_is_on(0, 1, &examples));
+        assert!(light_is_on(5, 5, &examples));
+    }
+
+    #[test]
+    fn calculate_brightness_examples() {
+        let examples = examples();
+        assert_eq!(calculate_brightness(0, 0, &examples), 3);
+        assert_eq!(calculate_brightness(0, 1, &examples), 1);
+        assert_eq!(calculate_brightness(5, 5, &examples), 1);
+    }
+
+    #[test]
+    fn sweep_matches_every_light() {
+        // Overlapping, nested and edge-sharing rectangles, some running off
+        // the grid or lying entirely past it.
+        let instructions = parse_input(
+            "turn on 0,0 through 19,19
+toggle 5,5 through 24,9
+turn off 10,0 through 10,29
+toggle 0,0 through 29,29
+turn on 25,25 through 40,40
+toggle 3,12 through 3,12
+turn off 20,5 through 24,9
+toggle 30,0 through 35,29
+turn on 12,18 through 31,22
+toggle 0,20 through 9,35
+turn off 6,6 through 8,8
+toggle 6,6 through 8,8",
+        )
+        .unwrap();
+        let grid_size = 30;
+        let lights = (0..grid_size).flat_map(|x| (0..grid_size).map(move |y| (x, y)));
+        let lit = lights
+            .clone()
+            .filter(|(x, y)| light_is_on(*x, *y, &instructions))
+            .count();
+        let brightness = lights
+            .map(|(x, y)| calculate_brightness(x, y, &instructions))
+            .sum::<usize>();
+        assert_eq!(total_lit(grid_size, &instructions), lit);
+        assert_eq!(total_brightness(grid_size, &instructions), brightness);
+    }
+
+    #[test]
+    fn sweep_large_grids() {
+        let size = 1_000_000_000;
+        let instructions = vec![
+            Instruction::new(Action::On, Rectangle::new(0, 0, size - 1, size - 1)),
+            Instruction::new(Action::Toggle, Rectangle::new(0, 0, size - 1, 0)),
+            Instruction::new(Action::Off, Rectangle::new(10, 10, 19, 19)),
+        ];
+        assert_eq!(total_lit(size, &instructions), size * size - size - 100);
+        assert_eq!(
+            total_brightness(size, &instructions),
+            size * size + 2 * size - 100
+        );
+        assert_eq!(total_lit(0, &instructions), 0);
+    }
+
+    #[test]
+    fn sweep_ignores_lights_off_the_grid() {
+        let instructions = vec![
+            Instruction::new(Action::On, Rectangle::new(1001, 0, 1002, 999)),
+            Instruction::new(Action::Toggle, Rectangle::new(0, 1000, 999, 1005)),
+            Instruction::new(Action::On, Rectangle::new(998, 998, 1500, 1500)),
+        ];
+        assert_eq!(total_lit(1000, &instructions), 4);
+        assert_eq!(total_brightness(1000, &instructions), 4);
+        assert_eq!(total_lit(1000, &instructions[..1]), 0);
+    }
+
+    #[test]
+    fn parse_input_errors() {
+        let error = parse_input("turn on 0,0 through 9,9\nflip 1,1 through 2,2").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+        let error = parse_input("toggle 0,0 through 9,y").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 22, "y"));
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, Gate>;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_gates(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        signal_on_wire(input, "a", HashMap::new())
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let a_signal = signal_on_wire(input, "a", HashMap::new())?;
+        let overrides = HashMap::from([("b", a_signal)]);
+        signal_on_wire(input, "a", overrides)
+    }
+}
+
+fn signal_on_wire(
+    gates: &HashMap<String, Gate>,
+    wire: &str,
+    overrides: HashMap<&str, u16>,
+) -> Option<u16> {
+    let mut outputs = gates
+        .keys()
+        .map(|key| (key.to_owned(), overrides.get(key.as_str()).copied()))
+        .collect::<HashMap<_, Option<u16>>>();
+    let gate = gates.get(wire)?;
+    Some(gate.get_output(gates, &mut outputs))
+}
+
+fn parse_gates(input: &str) -> Result<HashMap<String, Gate>, ParseError> {
+    let u16_max = u16::MAX.to_string();
+    let mut gates = HashMap::new();
+    let mut operands = Vec::new();
+    for line in input.lines() {
+        let instruction = line.split(' ').collect::<Vec<_>>();
+        let (output, gate) = match instruction[..] {
+            [input, "->", output] => {
+                operands.push(input);
+                (output, Gate::new(input, input, Operator::Nop))
+            }
+            ["NOT", input, "->", output] => {
+                operands.push(input);
+                (output, Gate::new(input, &u16_max, Operator::Xor))
+            }
+            [input_1, operator, input_2, "->", output] => {
+                let operator = parse_token(input, operator, "a gate operator")?;
+                operands.extend([input_1, input_2]);
+                (output, Gate::new(input_1, input_2, operator))
+            }
+            _ => return Err(ParseError::at(input, line, "a wire instruction")),
+        };
+        gates.insert(output.to_owned(), gate);
+    }
+    let unconnected = operands
+        .into_iter()
+        .find(|operand| operand.parse::<u16>().is_err() && !gates.contains_key(*operand));
+    match unconnected {
+        Some(operand) => Err(ParseError::at(
+            input,
+            operand,
+            "a signal or a connected wire",
+        )),
+        None => Ok(gates),
+    }
+}
+
+#[derive(Debug)]
+pub struct Gate {
+    input_1: String,
+    input_2: String,
+    operator: Operator,
+}
+
+#[derive(Debug)]
+enum Operator {
+    Nop,
+    Xor,
+    And,
+    Or,
+    Lshift,
+    Rshift,
+}
+
+impl FromStr for Operator {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Operator, Self::Err> {
+        match input {
+            "NOP" => Ok(Operator::Nop),
+            "XOR" => Ok(Operator::Xor),
+            "AND" => Ok(Operator::And),
+            "OR" => Ok(Operator::Or),
+            "LSHIFT" => Ok(Operator::Lshift),
+            "RSHIFT" => Ok(Operator::Rshift),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Gate {
+    fn new(input_1: &str, input_2: &str, operator: Operator) -> Self {
+        Gate {
+            input_1: input_1.to_string(),
+            input_2: input_2.to_owned(),
+            operator,
+        }
+    }
+
+    fn get_input(
+        input: &str,
+        gates: &HashMap<String, Gate>,
+        outputs: &mut HashMap<String, Option<u16>>,
+    ) -> u16 {
+        match input.parse::<u16>() {
+            Ok(input) => input,
+            Err(_) => match outputs.get(input).unwrap() {
+                Some(value) => *value,
+                None => {
+                    let output = gates.get(input).unwrap().get_output(gates, outputs);
+                    outputs.insert(input.to_owned(), Some(output));
+                    output
+                }
+            },
+        }
+    }
+
+    fn get_output(
+        &self,
+        gates: &HashMap<String, Gate>,
+        outputs: &mut HashMap<String, Option<u16>>,
+    ) -> u16 {
+        use Operator::*;
+        let in_1 = Gate::get_input(&self.input_1, gates, outputs);
+        let in_2 = Gate::get_input(&self.input_2, gates, outputs);
+        match self.operator {
+            Nop => in_1,
+            Xor => in_1 ^ in_2,
+            And => in_1 & in_2,
+            Or => in_1 | in_2,
+            Lshift => in_1 << in_2,
+            Rshift => in_1 >> in_2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> HashMap<String, Gate> {
+        use Operator::*;
+        let u16_max = u16::MAX.to_string();
+        HashMap::from([
+            ("x".to_owned(), Gate::new("123", "123", Nop)),
+            ("y".to_owned(), Gate::new("456", "456", Nop)),
+            ("d".to_owned(), Gate::new("x", "y", And)),
+            ("e".to_owned(), Gate::new("x", "y", Or)),
+            ("f".to_owned(), Gate::new("x", "2", Lshift)),
+            ("g".to_owned(), Gate::new("y", "2", Rshift)),
+            ("h".to_owned(), Gate::new("x", &u16_max, Xor)),
+            ("i".to_owned(), Gate::new("y", &u16_max, Xor)),
+        ])
+    }
+
+    #[test]
+    fn test_examples() {
+        let example_gates = example();
+        let mut outputs = example_gates
+            .keys()
+            .map(|key| (key.to_owned(), None))
+            .collect::<HashMap<_, Option<u16>>>();
+
+        let results = example_gates
+            .iter()
+            .map(|(output, gate)| {
+                let gate_output = gate.get_output(&example_gates, &mut outputs);
+                (output.to_owned(), Some(gate_output))
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(
+            results,
+            HashMap::from([
+                ("d".to_owned(), Some(72)),
+                ("e".to_owned(), Some(507)),
+                ("f".to_owned(), Some(492)),
+                ("g".to_owned(), Some(114)),
+                ("h".to_owned(), Some(65412)),
+                ("i".to_owned(), Some(65079)),
+                ("x".to_owned(), Some(123)),
+                ("y".to_owned(), Some(456)),
+            ])
+        )
+    }
+
+    #[test]
+    fn parse_gates_errors() {
+        let error = parse_gates("123 -> x\nx AMD 2 -> y").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (2, 3, "AMD"));
+        let error = parse_gates("123 -> x\nx OR z -> y").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (2, 6, "z"));
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Literal>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let memory = count_memory(line).map_err(|token| {
+                    ParseError::at(input, token, "a quoted string with valid escapes")
+                })?;
+                Ok(Literal {
+                    code: count_code(line),
+                    memory,
+                    new_encoding: count_new_encoding(line),
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(
+            input
+                .iter()
+                .map(|literal| literal.code - literal.memory)
+                .sum(),
+        )
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(
+            input
+                .iter()
+                .map(|literal| literal.new_encoding - literal.code)
+                .sum(),
+        )
+    }
+}
+
+pub struct Literal {
+    code: usize,
+    memory: usize,
+    new_encoding: usize,
+}
+
+fn count_code(input: &str) -> usize {
+    input.len()
+}
+
+fn count_memory(input: &str) -> Result<usize, &str> {
+    let contents = input
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or(input)?;
+    let mut total = 0;
+    let mut chars = contents.char_indices();
+    while let Some((start, next)) = chars.next() {
+        if next == '\\' {
+            let is_valid = match chars.next() {
+                Some((_, '"' | '\\')) => true,
+                Some((_, 'x')) => {
+                    let hex_digits = chars.by_ref().take(2);
+                    hex_digits.filter(|(_, c)| c.is_ascii_hexdigit()).count() == 2
+                }
+                _ => false,
+            };
+            if !is_valid {
+                return Err(&contents[start..chars.offset()]);
+            }
+        }
+        total += 1;
+    }
+    Ok(total)
+}
+
+fn count_new_encoding(input: &str) -> usize {
+    let additional_quotes = 2;
+    let encoded_len = input.chars().fold(0, |acc, next| match next {
+        '"' | '\\' => acc + 2,
+        _ => acc + 1,
+    });
+    encoded_len + additional_quotes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn examples() -> Vec<String> {
+        vec![
+            r#""""#.to_owned(),
+            r#""abc""#.to_owned(),
+            r#""aaa\"aaa""#.to_owned(),
+            r#""\x27""#.to_owned(),
+        ]
+    }
+
+    #[test]
+    fn count_code_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|example| count_code(&example))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![2, 5, 10, 6])
+    }
+
+    #[test]
+    fn count_memory_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|example| count_memory(&example).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![0, 3, 7, 1])
+    }
+
+    #[test]
+    fn count_memory_invalid_examples() {
+        assert_eq!(count_memory("abc"), Err("abc"));
+        assert_eq!(count_memory(r#""a\qb""#), Err(r#"\q"#));
+        assert_eq!(count_memory(r#""\x4g""#), Err(r#"\x4g"#));
+        assert_eq!(count_memory(r#""\""#), Err(r#"\"#));
+    }
+
+    #[test]
+    fn count_new_encoding_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|example| count_new_encoding(&example))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![6, 9, 16, 11])
+    }
+}
@@ -0,0 +1,226 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+// The route table has a row for every set of cities, so it grows as 2^n.
+const MAX_CITIES: usize = 20;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Graph;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        input.shortest_route()
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        input.longest_route()
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Graph {
+    cities: Vec<String>,
+    distances: Vec<Vec<Option<u32>>>,
+}
+
+fn parse_graph(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::default();
+    for (idx, line) in input.lines().enumerate() {
+        let (from, to, distance) = parse_route(line).map_err(|error| error.with_line(idx + 1))?;
+        for name in [from, to] {
+            if graph.cities.len() == MAX_CITIES && !graph.cities.iter().any(|city| city == name) {
+                let expected = format!("one of at most {MAX_CITIES} cities");
+                return Err(ParseError::at(line, name, &expected).with_line(idx + 1));
+            }
+        }
+        graph.add_route(from, to, distance);
+    }
+    Ok(graph)
+}
+
+fn parse_route(line: &str) -> Result<(&str, &str, u32), ParseError> {
+    match line.split(' ').collect::<Vec<_>>()[..] {
+        [from, "to", to, "=", distance] => {
+            Ok((from, to, parse_token(line, distance, "a distance")?))
+        }
+        _ => Err(ParseError::at(line, line, "a route like 'A to B = 1'")),
+    }
+}
+
+impl Graph {
+    fn city(&mut self, name: &str) -> usize {
+        if let Some(idx) = self.cities.iter().position(|city| city == name) {
+            return idx;
+        }
+        self.cities.push(name.to_owned());
+        for distances in self.distances.iter_mut() {
+            distances.push(None);
+        }
+        self.distances.push(vec![None; self.cities.len()]);
+        self.cities.len() - 1
+    }
+
+    fn add_route(&mut self, from: &str, to: &str, distance: u32) {
+        let (from, to) = (self.city(from), self.city(to));
+        self.distances[from][to] = Some(distance);
+        self.distances[to][from] = Some(distance);
+    }
+
+    pub fn shortest_route(&self) -> Option<u64> {
+        self.best_route(u64::min)
+    }
+
+    pub fn longest_route(&self) -> Option<u64> {
+        self.best_route(u64::max)
+    }
+
+    // Held-Karp: best[visited][last] is the best distance of a route that
+    // visits exactly the cities in the `visited` bit set and ends at `last`.
+    // Routes may start anywhere, so every single city route starts at 0.
+    // Totals are kept wider than single roads so long routes cannot overflow.
+    fn best_route(&self, better: fn(u64, u64) -> u64) -> Option<u64> {
+        let n = self.cities.len();
+        if n == 0 || n > MAX_CITIES {
+            return None;
+        }
+        let mut best = vec![vec![None; n]; 1 << n];
+        for city in 0..n {
+            best[1 << city][city] = Some(0);
+        }
+        for visited in 1..(1usize << n) {
+            for last in 0..n {
+                let Some(distance) = best[visited][last] else {
+                    continue;
+                };
+                for next in (0..n).filter(|next| visited & (1 << next) == 0) {
+                    let Some(step) = self.distances[last][next] else {
+                        continue;
+                    };
+                    let entry = &mut best[visited | (1 << next)][next];
+                    let candidate = distance + u64::from(step);
+                    *entry = Some(entry.map_or(candidate, |current| better(current, candidate)));
+                }
+            }
+        }
+        best[(1 << n) - 1].iter().flatten().copied().reduce(better)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Graph {
+        parse_graph(
+            "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141",
+        )
+        .unwrap()
+    }
+
+    // Cities spaced one apart along a road, so the best routes are known.
+    fn road(cities: u32) -> Graph {
+        let mut graph = Graph::default();
+        for from in 0..cities {
+            for to in from + 1..cities {
+                graph.add_route(&from.to_string(), &to.to_string(), to - from);
+            }
+        }
+        graph
+    }
+
+    fn brute_force(graph: &Graph, better: fn(u64, u64) -> u64) -> Option<u64> {
+        fn visit(
+            graph: &Graph,
+            route: &mut Vec<usize>,
+            better: fn(u64, u64) -> u64,
+            best: &mut Option<u64>,
+        ) {
+            if route.len() == graph.cities.len() {
+                let distance = route
+                    .windows(2)
+                    .map(|pair| u64::from(graph.distances[pair[0]][pair[1]].unwrap()))
+                    .sum();
+                *best = Some(best.map_or(distance, |best| better(best, distance)));
+                return;
+            }
+            for city in 0..graph.cities.len() {
+                if !route.contains(&city) {
+                    route.push(city);
+                    visit(graph, route, better, best);
+                    route.pop();
+                }
+            }
+        }
+        let mut best = None;
+        visit(graph, &mut Vec::new(), better, &mut best);
+        best
+    }
+
+    #[test]
+    fn shortest_route_examples() {
+        assert_eq!(example().shortest_route(), Some(605));
+        assert_eq!(road(15).shortest_route(), Some(14));
+    }
+
+    #[test]
+    fn longest_route_examples() {
+        assert_eq!(example().longest_route(), Some(982));
+        let road = road(7);
+        assert_eq!(road.longest_route(), brute_force(&road, u64::max));
+    }
+
+    #[test]
+    fn long_roads_do_not_overflow() {
+        let graph = parse_graph(&format!(
+            "A to B = {0}\nB to C = {0}\nA to C = {0}",
+            u32::MAX
+        ))
+        .unwrap();
+        assert_eq!(graph.shortest_route(), Some(2 * u64::from(u32::MAX)));
+        assert_eq!(graph.longest_route(), brute_force(&graph, u64::max));
+    }
+
+    #[test]
+    fn routes_need_every_city() {
+        let graph = parse_graph("A to B = 1\nC to D = 1").unwrap();
+        assert_eq!(graph.shortest_route(), None);
+        assert_eq!(Graph::default().longest_route(), None);
+    }
+
+    #[test]
+    fn too_many_cities() {
+        assert_eq!(road(MAX_CITIES as u32 + 1).shortest_route(), None);
+        let routes = (1..=MAX_CITIES)
+            .map(|to| format!("0 to {to} = 1"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let error = parse_graph(&routes).unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (MAX_CITIES, 6, "20")
+        );
+        let last_line = routes.rfind('\n').unwrap();
+        assert!(parse_graph(&routes[..last_line]).is_ok());
+    }
+
+    #[test]
+    fn parse_graph_errors() {
+        let error = parse_graph("London to Dublin = 464\nDublin to Belfast = far").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (2, 21, "far")
+        );
+        let error = parse_graph("London - Dublin = 464").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+    }
+}
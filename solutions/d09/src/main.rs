@@ -0,0 +1,11 @@
+use aoc_core::{InputSource, Solution};
+use d09::Day09;
+
+fn main() {
+    let input = InputSource::from_args(Day09::DAY).read().unwrap();
+    let input = Day09::parse(&input).unwrap();
+    let shortest = Day09::part_1(&input).unwrap();
+    let longest = Day09::part_2(&input).unwrap();
+    println!("The shortest route Santa can take is {shortest}");
+    println!("The longest route Santa can take is {longest}");
+}
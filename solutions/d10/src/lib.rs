@@ -0,0 +1,82 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim();
+        match input.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((idx, c)) => Err(ParseError::at(
+                input,
+                &input[idx..idx + c.len_utf8()],
+                "a digit",
+            )),
+            None => Ok(input.to_owned()),
+        }
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(look_and_say(input, 40).len())
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(look_and_say(input, 50).len())
+    }
+}
+
+fn look_and_say(input: &str, iterations: usize) -> String {
+    let mut result = input.to_owned();
+    for _ in 0..iterations {
+        result = process(&result);
+    }
+    result
+}
+
+fn process(input: &str) -> String {
+    let mut chars = input.chars().chain(['0']);
+    let first = chars.next().unwrap();
+    let (_, _, output) = chars.fold(
+        (first, 1, "".to_owned()),
+        |(prev, count, mut output), next| {
+            if next == prev {
+                (next, count + 1, output)
+            } else {
+                count.to_string().chars().for_each(|c| output.push(c));
+                output.push(prev);
+                (next, 1, output)
+            }
+        },
+    );
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::process;
+
+    fn examples() -> Vec<String> {
+        vec![
+            "1".to_string(),
+            "11".to_owned(),
+            "21".to_owned(),
+            "1211".to_owned(),
+            "111221".to_owned(),
+            "312211".to_owned(),
+        ]
+    }
+
+    #[test]
+    fn process_examples() {
+        let examples = examples();
+        for i in 0..examples.len() - 1 {
+            let result = process(&examples[i]);
+            assert_eq!(result, examples[i + 1])
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_core::{ParseError, Solution};
+use json::JsonValue;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = JsonValue;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        match json::parse(input) {
+            Ok(json) => Ok(json),
+            Err(json::Error::UnexpectedCharacter { ch, line, column }) => {
+                Err(ParseError::new(line, column, &ch.to_string(), "valid JSON"))
+            }
+            Err(_) => Err(ParseError::at(input, &input[input.len()..], "valid JSON")),
+        }
+    }
+
+    fn part_1(json: &Self::Input) -> Option<Self::Part1> {
+        sum_all_numbers(json)
+    }
+
+    fn part_2(json: &Self::Input) -> Option<Self::Part2> {
+        sum_non_red_numbers_impl(json)
+    }
+}
+
+fn sum_all_numbers(json: &JsonValue) -> Option<i32> {
+    sum_numbers(json, false)
+}
+
+fn sum_non_red_numbers_impl(json: &JsonValue) -> Option<i32> {
+    sum_numbers(json, true)
+}
+
+// No sum for numbers that are not integers or do not fit, whether on their
+// own or added together.
+fn sum_numbers(json: &JsonValue, skip_red: bool) -> Option<i32> {
+    if json.is_array() {
+        sum_values(json.members(), skip_red)
+    } else if json.is_object() {
+        if skip_red && json.entries().any(|(_, value)| value == "red") {
+            Some(0)
+        } else {
+            sum_values(json.entries().map(|(_, value)| value), skip_red)
+        }
+    } else if json.is_number() {
+        json.as_i32()
+    } else {
+        Some(0)
+    }
+}
+
+fn sum_values<'a>(mut values: impl Iterator<Item = &'a JsonValue>, skip_red: bool) -> Option<i32> {
+    values.try_fold(0i32, |sum, value| {
+        sum.checked_add(sum_numbers(value, skip_red)?)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sum_non_red_numbers(input: &str) -> Option<i32> {
+        let parsed_input = json::parse(input).unwrap();
+        sum_non_red_numbers_impl(&parsed_input)
+    }
+
+    fn examples() -> Vec<String> {
+        vec![
+            r#"[1,2,3]"#.to_owned(),
+            r#"{"a":2,"b":4}"#.to_owned(),
+            r#"[[[3]]]"#.to_owned(),
+            r#"{"a":{"b":4},"c":-1}"#.to_owned(),
+            r#"{"a":[-1,1]}"#.to_owned(),
+            r#"[-1,{"a":1}]"#.to_owned(),
+            r#"[]"#.to_owned(),
+            r#"{}"#.to_owned(),
+            r#"[1,{"c":"red","b":2},3]"#.to_owned(),
+            r#"{"d":"red","e":[1,2,3,4],"f":5}"#.to_owned(),
+            r#"[1,"red",5]"#.to_owned(),
+        ]
+    }
+
+    #[test]
+    fn sum_all_numbers_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|example| sum_all_numbers(&json::parse(&example).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![6, 6, 3, 3, 0, 0, 0, 0, 6, 15, 6])
+    }
+
+    #[test]
+    fn sum_non_red_numbers_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|example| sum_non_red_numbers(&example).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![6, 6, 3, 3, 0, 0, 0, 0, 4, 0, 6])
+    }
+
+    #[test]
+    fn numbers_only_come_from_numbers() {
+        for input in [r#"["a-b",1]"#, r#"{"x":"-","y":1}"#, r#"["2²",1]"#] {
+            let json = Day12::parse(input).unwrap();
+            assert_eq!(Day12::part_1(&json), Some(1), "{input}");
+            assert_eq!(Day12::part_2(&json), Some(1), "{input}");
+        }
+    }
+
+    #[test]
+    fn unsummable_numbers() {
+        for input in [
+            "[1.5]",
+            "[2147483648]",
+            "[2147483647,1]",
+            r#"{"a":[-2147483648,-1]}"#,
+        ] {
+            let json = Day12::parse(input).unwrap();
+            assert_eq!(Day12::part_1(&json), None, "{input}");
+            assert_eq!(Day12::part_2(&json), None, "{input}");
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Table;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_table(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(input.optimal_seating()?.happiness)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(input.with_yourself().optimal_seating()?.happiness)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Table {
+    guests: Vec<String>,
+    // happiness[a][b] is how much a's happiness changes when seated next to b.
+    happiness: Vec<Vec<i32>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Seating {
+    pub order: Vec<String>,
+    pub happiness: i32,
+}
+
+fn parse_table(input: &str) -> Result<Table, ParseError> {
+    let mut table = Table::default();
+    for (idx, line) in input.lines().enumerate() {
+        let (guest, neighbour, happiness) =
+            parse_preference(line).map_err(|error| error.with_line(idx + 1))?;
+        table.add_preference(guest, neighbour, happiness);
+    }
+    Ok(table)
+}
+
+fn parse_preference(line: &str) -> Result<(&str, &str, i32), ParseError> {
+    let words = line.split(' ').collect::<Vec<_>>();
+    match words[..] {
+        [guest, "would", direction, amount, "happiness", "units", "by", "sitting", "next", "to", neighbour] =>
+        {
+            let amount: i32 = parse_token(line, amount, "an amount of happiness")?;
+            let happiness = match direction {
+                "gain" => amount,
+                "lose" => -amount,
+                _ => return Err(ParseError::at(line, direction, "gain or lose")),
+            };
+            let neighbour = neighbour
+                .strip_suffix('.')
+                .ok_or_else(|| ParseError::at(line, neighbour, "a name followed by '.'"))?;
+            Ok((guest, neighbour, happiness))
+        }
+        _ => Err(ParseError::at(line, line, "a seating preference")),
+    }
+}
+
+impl Table {
+    fn guest(&mut self, name: &str) -> usize {
+        if let Some(idx) = self.guests.iter().position(|guest| guest == name) {
+            return idx;
+        }
+        self.guests.push(name.to_owned());
+        for happiness in self.happiness.iter_mut() {
+            happiness.push(0);
+        }
+        self.happiness.push(vec![0; self.guests.len()]);
+        self.guests.len() - 1
+    }
+
+    fn add_preference(&mut self, guest: &str, neighbour: &str, happiness: i32) {
+        let (guest, neighbour) = (self.guest(guest), self.guest(neighbour));
+        self.happiness[guest][neighbour] = happiness;
+    }
+
+    // A guest who neither minds nor is minded by anyone.
+    pub fn with_yourself(&self) -> Table {
+        let mut table = self.clone();
+        table.guest("Yourself");
+        table
+    }
+
+    fn pair(&self, a: usize, b: usize) -> i32 {
+        self.happiness[a][b] + self.happiness[b][a]
+    }
+
+    // Held-Karp over the guests after the first, who sits at the head of the
+    // table since every rotation of a circular seating is equally happy.
+    // best[seated][last] is the happiest way of seating the guests in the
+    // `seated` bit set after the first, ending with `last`, along with the
+    // guest seated before `last`.
+    pub fn optimal_seating(&self) -> Option<Seating> {
+        let n = self.guests.len();
+        if n == 0 {
+            return None;
+        }
+        let mut best: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; n]; 1 << n];
+        best[1][0] = Some((0, 0));
+        for seated in (1..(1usize << n)).step_by(2) {
+            for last in 0..n {
+                let Some((happiness, _)) = best[seated][last] else {
+                    continue;
+                };
+                for next in (0..n).filter(|next| seated & (1 << next) == 0) {
+                    let candidate = happiness + self.pair(last, next);
+                    let entry = &mut best[seated | (1 << next)][next];
+                    if entry.is_none_or(|(current, _)| candidate > current) {
+                        *entry = Some((candidate, last));
+                    }
+                }
+            }
+        }
+        let everyone = (1 << n) - 1;
+        let (happiness, mut last) = (0..n)
+            .filter_map(|last| {
+                let (happiness, _) = best[everyone][last]?;
+                let closing = if n > 1 { self.pair(last, 0) } else { 0 };
+                Some((happiness + closing, last))
+            })
+            .max_by_key(|(happiness, _)| *happiness)?;
+
+        let mut order = Vec::with_capacity(n);
+        let mut seated = everyone;
+        while seated != 0 {
+            order.push(self.guests[last].clone());
+            let (_, previous) = best[seated][last]?;
+            seated &= !(1 << last);
+            last = previous;
+        }
+        order.reverse();
+        Some(Seating { order, happiness })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Table {
+        parse_table(
+            "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.",
+        )
+        .unwrap()
+    }
+
+    fn happiness_of(table: &Table, order: &[String]) -> i32 {
+        let seats = order
+            .iter()
+            .map(|name| table.guests.iter().position(|guest| guest == name).unwrap())
+            .collect::<Vec<_>>();
+        (0..seats.len())
+            .map(|idx| table.pair(seats[idx], seats[(idx + 1) % seats.len()]))
+            .sum()
+    }
+
+    #[test]
+    fn optimal_seating_examples() {
+        let table = example();
+        let seating = table.optimal_seating().unwrap();
+        assert_eq!(seating.happiness, 330);
+        assert_eq!(seating.order, vec!["Alice", "Bob", "Carol", "David"]);
+        assert_eq!(happiness_of(&table, &seating.order), 330);
+    }
+
+    #[test]
+    fn with_yourself_examples() {
+        let table = example().with_yourself();
+        let seating = table.optimal_seating().unwrap();
+        assert_eq!(seating.order.len(), 5);
+        assert!(seating.order.contains(&"Yourself".to_owned()));
+        assert_eq!(happiness_of(&table, &seating.order), seating.happiness);
+        assert_eq!(seating.happiness, 286);
+    }
+
+    #[test]
+    fn small_tables() {
+        assert_eq!(Table::default().optimal_seating(), None);
+        let table =
+            parse_table("Alice would gain 1 happiness units by sitting next to Bob.").unwrap();
+        let seating = table.optimal_seating().unwrap();
+        assert_eq!(seating.order, vec!["Alice", "Bob"]);
+        // Two guests sit next to each other on both sides.
+        assert_eq!(seating.happiness, 2);
+    }
+
+    #[test]
+    fn parse_table_errors() {
+        let error = parse_table(
+            "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would win 79 happiness units by sitting next to Carol.",
+        )
+        .unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (2, 13, "win")
+        );
+        let error =
+            parse_table("Alice would gain 54 happiness units by sitting next to Bob").unwrap_err();
+        assert_eq!(error.token(), "Bob");
+    }
+}
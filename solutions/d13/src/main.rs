@@ -0,0 +1,19 @@
+use aoc_core::{InputSource, Solution};
+use d13::Day13;
+
+fn main() {
+    let input = InputSource::from_args(Day13::DAY).read().unwrap();
+    let input = Day13::parse(&input).unwrap();
+    let seating = input.optimal_seating().unwrap();
+    println!(
+        "Seating {} gives a total change in happiness of {}",
+        seating.order.join(", "),
+        seating.happiness
+    );
+    let seating = input.with_yourself().optimal_seating().unwrap();
+    println!(
+        "With yourself, seating {} gives a total change in happiness of {}",
+        seating.order.join(", "),
+        seating.happiness
+    );
+}
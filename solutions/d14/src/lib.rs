@@ -0,0 +1,213 @@
+use std::cmp::min;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+const END_TIME: usize = 2503;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Reindeer>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_contestants(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        part_1_winner(END_TIME, input)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        part_2_winner(END_TIME, input)
+    }
+}
+
+fn parse_contestants(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Reindeer::from_str(line).map_err(|error| error.with_line(idx + 1)))
+        .collect()
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Reindeer {
+    name: String,
+    fly_speed: usize,
+    fly_time: usize,
+    rest_time: usize,
+}
+
+impl Reindeer {
+    fn new(name: &str, fly_speed: usize, fly_time: usize, rest_time: usize) -> Reindeer {
+        Reindeer {
+            name: name.to_string(),
+            fly_speed,
+            fly_time,
+            rest_time,
+        }
+    }
+
+    fn from_str(riendeer_description: &str) -> Result<Reindeer, ParseError> {
+        let description = riendeer_description.split(' ').collect::<Vec<_>>();
+        match description[..] {
+            [name, "can", "fly", fly_speed, "km/s", "for", fly_time, "seconds,", "but", "then", "must", "rest", "for", rest_time, "seconds."] =>
+            {
+                let fly_speed = parse_token(riendeer_description, fly_speed, "a speed")?;
+                let fly_time = parse_token(riendeer_description, fly_time, "a duration")?;
+                let rest_time = parse_token(riendeer_description, rest_time, "a duration")?;
+                Ok(Reindeer::new(name, fly_speed, fly_time, rest_time))
+            }
+            _ => Err(ParseError::at(
+                riendeer_description,
+                riendeer_description,
+                "a reindeer description",
+            )),
+        }
+    }
+
+    fn part_1_distance(&self, mut t: usize) -> usize {
+        let mut distance = 0;
+        let mut is_resting = false;
+        while t > 0 {
+            if is_resting {
+                let time_rested = min(t, self.rest_time);
+                t -= time_rested;
+                is_resting = false;
+            } else {
+                let time_traveled = min(t, self.fly_time);
+                distance += self.fly_speed * time_traveled;
+                t -= time_traveled;
+                is_resting = true;
+            }
+        }
+        distance
+    }
+
+    fn part_2_distance<'a>(&'a self, t: usize) -> impl Iterator<Item = usize> + 'a {
+        let mut distance = 0;
+        let mut step = 0;
+        let mut is_resting = false;
+        let mut phase_time = self.fly_time;
+
+        std::iter::from_fn(move || {
+            if step < t {
+                if phase_time == 0 {
+                    match is_resting {
+                        true => phase_time = self.fly_time,
+                        false => phase_time = self.rest_time,
+                    }
+                    is_resting = !is_resting
+                }
+                let step_size = self.step(is_resting);
+                distance += step_size;
+                phase_time -= 1;
+                step += 1;
+                Some(distance)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn step(&self, is_resting: bool) -> usize {
+        if is_resting {
+            0
+        } else {
+            self.fly_speed
+        }
+    }
+}
+
+fn part_1_winner(end_time: usize, contestants: &[Reindeer]) -> Option<usize> {
+    contestants
+        .iter()
+        .map(|r| r.part_1_distance(end_time))
+        .max()
+}
+
+fn part_2_winner(end_time: usize, contestants: &[Reindeer]) -> Option<usize> {
+    let mut distance_iters = contestants
+        .iter()
+        .map(|r| r.part_2_distance(end_time))
+        .collect::<Vec<_>>();
+    let mut totals = vec![0usize; distance_iters.len()];
+    for _ in 0..end_time {
+        let distances_at_i = distance_iters
+            .iter_mut()
+            .map(|d| d.next().unwrap_or(0))
+            .collect::<Vec<_>>();
+        let max = distances_at_i.iter().max()?;
+        distances_at_i
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| *d == max)
+            .map(|(idx, _)| idx)
+            .for_each(|idx| {
+                let total = totals.get_mut(idx).unwrap();
+                *total += 1;
+            })
+    }
+    totals.into_iter().max()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn examples() -> Vec<Reindeer> {
+        vec![
+            Reindeer::new("Comet", 14, 10, 127),
+            Reindeer::new("Dancer", 16, 11, 162),
+        ]
+    }
+
+    #[test]
+    fn distance_examples() {
+        let examples = examples();
+        let results = examples
+            .into_iter()
+            .map(|r| r.part_1_distance(1000))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![1120, 1056]);
+    }
+
+    #[test]
+    fn part_1_winner_examples() {
+        let examples = examples();
+        let result = part_1_winner(1000, &examples);
+        if let Some(d) = result {
+            assert_eq!(d, 1120);
+        } else {
+            panic!("Should be a winner")
+        }
+    }
+
+    #[test]
+    fn part_2_winner_examples() {
+        let examples = examples();
+        let result = part_2_winner(1000, &examples);
+        if let Some(t) = result {
+            assert_eq!(t, 689);
+        } else {
+            panic!("Should be a winner")
+        }
+    }
+
+    #[test]
+    fn parse_contestants_errors() {
+        let input = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for a while.";
+        let error = parse_contestants(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+        let error = parse_contestants(&input.replace("for 10", "for ten")).unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (1, 27, "ten")
+        );
+    }
+}
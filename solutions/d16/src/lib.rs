@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+use Comparison::{Equal, Greater, Less};
+
+const TICKER_TAPE: &str = "children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, \
+vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1";
+
+// The outdated retroencabulator reads ranges for some compounds.
+const RETROENCABULATOR: &[(&str, Comparison)] = &[
+    ("cats", Greater),
+    ("trees", Greater),
+    ("pomeranians", Less),
+    ("goldfish", Less),
+];
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Vec<Sue>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_sues(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        find_sue(input, &ticker_tape(), &Rules::new(Equal))
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let rules = Rules::new(Equal).with_overrides(RETROENCABULATOR);
+        find_sue(input, &ticker_tape(), &rules)
+    }
+}
+
+pub type Things = BTreeMap<String, u32>;
+
+#[derive(Debug, PartialEq)]
+pub struct Sue {
+    number: u32,
+    things: Things,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    // The remembered amount must be greater than the reading.
+    Greater,
+    Less,
+}
+
+impl Comparison {
+    fn matches(self, remembered: u32, reading: u32) -> bool {
+        match self {
+            Equal => remembered == reading,
+            Greater => remembered > reading,
+            Less => remembered < reading,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    default: Comparison,
+    overrides: BTreeMap<String, Comparison>,
+}
+
+impl Rules {
+    pub fn new(default: Comparison) -> Self {
+        Rules {
+            default,
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_overrides(mut self, overrides: &[(&str, Comparison)]) -> Self {
+        for (thing, comparison) in overrides {
+            self.overrides.insert(thing.to_string(), *comparison);
+        }
+        self
+    }
+
+    fn comparison(&self, thing: &str) -> Comparison {
+        self.overrides.get(thing).copied().unwrap_or(self.default)
+    }
+}
+
+impl Sue {
+    // Things the tape has no reading for cannot be checked, so rule Sue out.
+    pub fn matches(&self, tape: &Things, rules: &Rules) -> bool {
+        self.things.iter().all(|(thing, &remembered)| {
+            tape.get(thing)
+                .is_some_and(|&reading| rules.comparison(thing).matches(remembered, reading))
+        })
+    }
+}
+
+// Only an unambiguous match identifies the Sue who sent the gift.
+pub fn find_sue(sues: &[Sue], tape: &Things, rules: &Rules) -> Option<u32> {
+    let mut matching = sues.iter().filter(|sue| sue.matches(tape, rules));
+    let sue = matching.next()?;
+    matching.next().is_none().then_some(sue.number)
+}
+
+fn ticker_tape() -> Things {
+    parse_things(TICKER_TAPE, TICKER_TAPE).expect("the ticker tape is well formed")
+}
+
+fn parse_sues(input: &str) -> Result<Vec<Sue>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_sue(line).map_err(|error| error.with_line(idx + 1)))
+        .collect()
+}
+
+fn parse_sue(line: &str) -> Result<Sue, ParseError> {
+    let (name, things) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(line, line, "'Sue N: ' followed by things"))?;
+    let number = match name.split_once(' ') {
+        Some(("Sue", number)) => parse_token(line, number, "a number")?,
+        _ => return Err(ParseError::at(line, name, "'Sue N'")),
+    };
+    let things = parse_things(line, things)?;
+    Ok(Sue { number, things })
+}
+
+// Errors are positioned within `source`, which `things` is a part of.
+fn parse_things(source: &str, things: &str) -> Result<Things, ParseError> {
+    things
+        .split(", ")
+        .map(|thing| match thing.split_once(": ") {
+            Some((name, amount)) => {
+                Ok((name.to_owned(), parse_token(source, amount, "an amount")?))
+            }
+            None => Err(ParseError::at(source, thing, "'thing: amount'")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn examples() -> Vec<Sue> {
+        parse_sues(
+            "Sue 1: cars: 9, akitas: 3, goldfish: 0
+Sue 2: children: 3, cats: 7, trees: 3
+Sue 3: cats: 8, trees: 4, goldfish: 4
+Sue 4: perfumes: 1, pomeranians: 3, cars: 2",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn exact_matching_examples() {
+        let sues = examples();
+        assert_eq!(find_sue(&sues, &ticker_tape(), &Rules::new(Equal)), None);
+        assert_eq!(
+            find_sue(&sues[..3], &ticker_tape(), &Rules::new(Equal)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn retroencabulator_examples() {
+        let sues = examples();
+        let rules = Rules::new(Equal).with_overrides(RETROENCABULATOR);
+        assert_eq!(find_sue(&sues, &ticker_tape(), &rules), Some(3));
+    }
+
+    #[test]
+    fn other_policies() {
+        let sues = examples();
+        // Remembering more than the reading, compound by compound, except for
+        // goldfish which must match exactly.
+        let tape = "cars: 2, akitas: 0, goldfish: 0";
+        let tape = parse_things(tape, tape).unwrap();
+        let rules = Rules::new(Greater).with_overrides(&[("goldfish", Equal)]);
+        assert_eq!(find_sue(&sues, &tape, &rules), Some(1));
+    }
+
+    #[test]
+    fn parse_sues_errors() {
+        let error = parse_sues("Sue 1: cars: 9\nSue 2: cars: many").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (2, 14, "many")
+        );
+        let error = parse_sues("Sue one: cars: 9").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 5, "one"));
+        let error = parse_sues("Sue 1: cars: 9, trees").unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.token()),
+            (1, 17, "trees")
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_core::{InputSource, Solution};
+use d16::Day16;
+
+fn main() {
+    let input = InputSource::from_args(Day16::DAY).read().unwrap();
+    let input = Day16::parse(&input).unwrap();
+    println!("Part 1: {}", Day16::part_1(&input).unwrap());
+    println!("Part 2: {}", Day16::part_2(&input).unwrap());
+}
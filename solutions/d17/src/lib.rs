@@ -0,0 +1,114 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+const TARGET: u32 = 150;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_token(input, line, "a container size"))
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut combinator = Combinator::new(TARGET);
+        combinator.combinations(input);
+        Some(combinator.total_combinations)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut combinator = Combinator::new(TARGET);
+        combinator.combinations(input);
+        let (_, total) = combinator.min_containers_used?;
+        Some(total)
+    }
+}
+
+struct Combinator {
+    target: u32,
+    total_combinations: u32,
+    min_containers_used: Option<(u32, u32)>,
+}
+
+impl Combinator {
+    fn new(target: u32) -> Self {
+        Self {
+            target,
+            total_combinations: 0,
+            min_containers_used: None,
+        }
+    }
+
+    fn combinations(&mut self, containers: &[u32]) {
+        self.total_combinations = self.combinations_impl(0, containers, 0);
+    }
+
+    fn combinations_impl(&mut self, acc: u32, remaining: &[u32], containers_used: u32) -> u32 {
+        if acc == self.target {
+            self.update_min_containers_used(containers_used);
+            return 1;
+        } else if remaining.is_empty() {
+            return 0;
+        }
+        let mut total = 0;
+        for (idx, next) in remaining.iter().enumerate() {
+            let new_acc = acc + next;
+            let new_remaining = &remaining[idx + 1..];
+            let new_containers_used = containers_used + 1;
+            total += self.combinations_impl(new_acc, new_remaining, new_containers_used);
+        }
+        total
+    }
+
+    fn update_min_containers_used(&mut self, containers_used: u32) {
+        match self.min_containers_used {
+            Some((min, acc)) => {
+                if containers_used == min {
+                    self.min_containers_used = Some((min, acc + 1));
+                } else if containers_used < min {
+                    self.set_min_containers_used(containers_used);
+                }
+            }
+            None => {
+                self.set_min_containers_used(containers_used);
+            }
+        }
+    }
+
+    fn set_min_containers_used(&mut self, containers_used: u32) {
+        self.min_containers_used = Some((containers_used, 1));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_containers() -> Vec<u32> {
+        vec![20, 15, 10, 5, 5]
+    }
+
+    #[test]
+    fn part_1_example() {
+        let example_containers = example_containers();
+        let mut combinator = Combinator::new(25);
+        combinator.combinations(&example_containers);
+        assert_eq!(4, combinator.total_combinations);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let example_containers = example_containers();
+        let mut combinator = Combinator::new(25);
+        combinator.combinations(&example_containers);
+        assert_eq!((2, 3), combinator.min_containers_used.unwrap());
+    }
+}
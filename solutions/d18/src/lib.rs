@@ -0,0 +1,201 @@
+use aoc_core::{ParseError, Solution};
+
+const STEPS: usize = 100;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = State;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_state(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut light_grid = LightGrid::new(Grid::Regular, input.clone());
+        light_grid.step(STEPS);
+        Some(light_grid.count_total_lit())
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut light_grid = LightGrid::new(Grid::Broken, input.clone());
+        light_grid.step(STEPS);
+        Some(light_grid.count_total_lit())
+    }
+}
+
+pub type State = Vec<Vec<bool>>;
+
+struct LightGrid {
+    grid: Grid,
+    state: State,
+}
+
+impl LightGrid {
+    fn new(grid: Grid, state: State) -> Self {
+        let state = grid.set_state(state);
+        Self { grid, state }
+    }
+
+    #[cfg(test)]
+    fn from_str(grid: Grid, grid_str: &str) -> Option<Self> {
+        let state = parse_state(grid_str).ok()?;
+        Some(Self::new(grid, state))
+    }
+
+    fn step(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step_once();
+        }
+    }
+
+    fn step_once(&mut self) {
+        let mut next_state = self.state.clone();
+        for (i, row) in self.state.iter().enumerate() {
+            for (j, light) in row.iter().enumerate() {
+                let ln = self.count_lit_neighbours(i, j);
+                if *light && !(2..=3).contains(&ln) {
+                    next_state[i][j] = false;
+                } else if !light && ln == 3 {
+                    next_state[i][j] = true;
+                }
+            }
+        }
+        let next_state = self.grid.set_state(next_state);
+        self.state = next_state;
+    }
+
+    fn count_lit_neighbours(&self, i: usize, j: usize) -> u8 {
+        let mut lit_neighbours = 0;
+        let size = self.state.len();
+        for x in 0.max(i as i32 - 1) as usize..size.min(i + 2) {
+            for y in 0.max(j as i32 - 1) as usize..size.min(j + 2) {
+                if (x != i || y != j) && self.state[x][y] {
+                    lit_neighbours += 1;
+                }
+            }
+        }
+        lit_neighbours
+    }
+
+    fn count_total_lit(&self) -> usize {
+        self.state.iter().fold(0, |acc, row| {
+            acc + row.iter().filter(|light| **light).count()
+        })
+    }
+}
+
+fn parse_state(grid_str: &str) -> Result<State, ParseError> {
+    let grid_size = grid_str.lines().count();
+    let mut state = Vec::new();
+    for line in grid_str.lines() {
+        let mut row = Vec::new();
+        for (idx, c) in line.char_indices() {
+            match c {
+                '#' => row.push(true),
+                '.' => row.push(false),
+                _ => {
+                    let token = &line[idx..idx + c.len_utf8()];
+                    return Err(ParseError::at(grid_str, token, "'#' or '.'"));
+                }
+            }
+        }
+        if row.len() != grid_size {
+            let expected = format!("a square grid with {grid_size} lights per row");
+            return Err(ParseError::at(grid_str, line, &expected));
+        }
+        state.push(row);
+    }
+    Ok(state)
+}
+
+enum Grid {
+    Regular,
+    Broken,
+}
+
+impl Grid {
+    fn set_state(&self, mut state: State) -> State {
+        match self {
+            Grid::Regular => (),
+            Grid::Broken => {
+                let end = state.len() - 1;
+                state[0][0] = true;
+                state[0][end] = true;
+                state[end][0] = true;
+                state[end][end] = true;
+            }
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_state() -> Vec<Vec<bool>> {
+        vec![
+            vec![false, true, false, true, false, true],
+            vec![false, false, false, true, true, false],
+            vec![true, false, false, false, false, true],
+            vec![false, false, true, false, false, false],
+            vec![true, false, true, false, false, true],
+            vec![true, true, true, true, false, false],
+        ]
+    }
+
+    #[test]
+    fn from_str() {
+        let expected_state = example_state();
+        let grid_str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+        let light_grid = LightGrid::from_str(Grid::Regular, grid_str);
+        assert!(light_grid.is_some());
+        let light_grid = light_grid.unwrap();
+        assert_eq!(light_grid.state, expected_state);
+    }
+
+    #[test]
+    fn count_lit_neighbours() {
+        let example_state = example_state();
+        let end = example_state.len() - 1;
+        let light_grid = LightGrid::new(Grid::Regular, example_state);
+        assert_eq!(1, light_grid.count_lit_neighbours(0, 0));
+        assert_eq!(1, light_grid.count_lit_neighbours(0, end));
+        assert_eq!(2, light_grid.count_lit_neighbours(end, 0));
+        assert_eq!(1, light_grid.count_lit_neighbours(end, end));
+        assert_eq!(2, light_grid.count_lit_neighbours(1, 1));
+        assert_eq!(4, light_grid.count_lit_neighbours(1, end - 1));
+        assert_eq!(6, light_grid.count_lit_neighbours(end - 1, 1));
+        assert_eq!(2, light_grid.count_lit_neighbours(end - 1, end - 1));
+    }
+
+    #[test]
+    fn count_total_lit() {
+        let example_state = example_state();
+        let light_grid = LightGrid::new(Grid::Regular, example_state);
+        assert_eq!(15, light_grid.count_total_lit());
+    }
+
+    #[test]
+    fn part_1_example() {
+        let example_state = example_state();
+        let mut light_grid = LightGrid::new(Grid::Regular, example_state);
+        light_grid.step(4);
+        let total_lit = light_grid.count_total_lit();
+        assert_eq!(4, total_lit);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let example_state = example_state();
+        let mut light_grid = LightGrid::new(Grid::Broken, example_state);
+        light_grid.step(5);
+        let total_lit = light_grid.count_total_lit();
+        assert_eq!(17, total_lit);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use aoc_core::{ParseError, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (Manipulator, String);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (replacements_str, molecule) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at(
+                input,
+                &input[input.len()..],
+                "a blank line followed by the medicine molecule",
+            )
+        })?;
+        let manipulator = Manipulator::from_str(replacements_str)?;
+        Ok((manipulator, molecule.trim().to_owned()))
+    }
+
+    fn part_1((manipulator, molecule): &Self::Input) -> Option<Self::Part1> {
+        Some(manipulator.calibrate(molecule))
+    }
+
+    fn part_2((manipulator, molecule): &Self::Input) -> Option<Self::Part2> {
+        manipulator.construct_molecule("e", molecule)
+    }
+}
+
+type Replacements = Vec<(String, String)>;
+
+#[derive(Clone)]
+pub struct Manipulator {
+    replacements: Replacements,
+}
+
+impl Manipulator {
+    fn new(replacements: Replacements) -> Self {
+        Self { replacements }
+    }
+
+    fn from_str(replacements_str: &str) -> Result<Self, ParseError> {
+        let mut replacements = Vec::new();
+        for line in replacements_str.lines() {
+            let rule = line.split(' ').collect::<Vec<_>>();
+            match rule[..] {
+                [target, "=>", replacement] => {
+                    replacements.push((target.to_owned(), replacement.to_owned()))
+                }
+                _ => {
+                    return Err(ParseError::at(
+                        replacements_str,
+                        line,
+                        "a replacement in the form 'target => replacement'",
+                    ))
+                }
+            }
+        }
+        Ok(Self::new(replacements))
+    }
+
+    fn calibrate(&self, molecule: &str) -> usize {
+        let matches = self.get_distinct_replacements(molecule);
+        matches.len()
+    }
+
+    fn get_distinct_replacements(&self, molecule: &str) -> HashSet<String> {
+        let mut matches = HashSet::new();
+        for (target, replacement) in &self.replacements {
+            let target_size = target.len();
+            for (idx, _) in molecule.match_indices(target) {
+                let replaced_molecule =
+                    molecule[..idx].to_owned() + replacement + &molecule[idx + target_size..];
+                matches.insert(replaced_molecule);
+            }
+        }
+        matches
+    }
+
+    // Works backwards from the target, each step undoing whichever replacement
+    // ends furthest right, preferring the longest, since growing every
+    // molecule forwards from the base branches far too widely to finish on
+    // real input. Only replacements that add elements are undone, so this
+    // always ends, giving up if nothing more can be undone short of the base.
+    fn construct_molecule(&self, base_molecule: &str, target_molecule: &str) -> Option<usize> {
+        let mut molecule = target_molecule.to_owned();
+        let mut step = 0;
+        while molecule != base_molecule {
+            if self
+                .replacements
+                .iter()
+                .any(|(target, replacement)| target == base_molecule && *replacement == molecule)
+            {
+                return Some(step + 1);
+            }
+            let (idx, target, replacement) = self
+                .replacements
+                .iter()
+                .filter(|(target, replacement)| {
+                    target != base_molecule && molecule_len(replacement) > molecule_len(target)
+                })
+                .filter_map(|(target, replacement)| {
+                    let idx = molecule.rfind(replacement.as_str())?;
+                    Some((idx, target, replacement))
+                })
+                .max_by_key(|(idx, _, replacement)| (idx + replacement.len(), replacement.len()))?;
+            molecule.replace_range(idx..idx + replacement.len(), target);
+            step += 1;
+        }
+        Some(step)
+    }
+}
+
+fn molecule_len(molecule: &str) -> usize {
+    molecule.chars().filter(|c| c.is_ascii_uppercase()).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_replacements() -> Replacements {
+        vec![
+            ("e".to_owned(), "H".to_owned()),
+            ("e".to_owned(), "O".to_owned()),
+            ("H".to_owned(), "HO".to_owned()),
+            ("H".to_owned(), "OH".to_owned()),
+            ("O".to_owned(), "HH".to_owned()),
+        ]
+    }
+
+    #[test]
+    fn part_1_example_1() {
+        let example_replacements = example_replacements();
+        let base_molecule = "HOH";
+        let manipulator = Manipulator::new(example_replacements);
+        let result = manipulator.calibrate(base_molecule);
+        assert_eq!(4, result);
+    }
+
+    #[test]
+    fn part_1_example_2() {
+        let example_replacements = example_replacements();
+        let base_molecule = "HOHOHO";
+        let manipulator = Manipulator::new(example_replacements);
+        let result = manipulator.calibrate(base_molecule);
+        assert_eq!(7, result);
+    }
+
+    #[test]
+    fn part_2_example_1() {
+        let example_replacements = example_replacements();
+        let base_molecule = "e";
+        let target_molecule = "HOH";
+        let manipulator = Manipulator::new(example_replacements);
+        let result = manipulator.construct_molecule(base_molecule, target_molecule);
+        assert_eq!(Some(3), result);
+    }
+
+    #[test]
+    fn part_2_example_2() {
+        let example_replacements = example_replacements();
+        let base_molecule = "e";
+        let target_molecule = "HOHOHO";
+        let manipulator = Manipulator::new(example_replacements);
+        let result = manipulator.construct_molecule(base_molecule, target_molecule);
+        assert_eq!(Some(6), result);
+    }
+
+    #[test]
+    fn part_2_unreachable() {
+        let manipulator = Manipulator::new(example_replacements());
+        assert_eq!(manipulator.construct_molecule("e", "HOX"), None);
+        assert_eq!(manipulator.construct_molecule("e", ""), None);
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Fighter;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_boss(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        let (winners, _) = fight_all_loadouts(input);
+        winners.into_iter().min()
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let (_, losers) = fight_all_loadouts(input);
+        losers.into_iter().max()
+    }
+}
+
+fn parse_boss(input: &str) -> Result<Fighter, ParseError> {
+    let boss_parameters = input
+        .lines()
+        .map(|l| match l.split_once(':') {
+            Some((_, v)) => parse_token(input, v.trim(), "a number"),
+            None => Err(ParseError::at(
+                input,
+                l,
+                "a boss stat in the form 'Name: value'",
+            )),
+        })
+        .collect::<Result<Vec<i32>, _>>()?;
+    match boss_parameters[..] {
+        [health, damage, protection] => Ok(Fighter::new(health, 0, damage, protection)),
+        _ => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "hit points, damage and armor",
+        )),
+    }
+}
+
+fn fight_all_loadouts(boss: &Fighter) -> (Vec<i32>, Vec<i32>) {
+    let empty = (0, 0, 0);
+    let weapons = [(8, 4, 0), (10, 5, 0), (25, 6, 0), (40, 7, 0), (74, 8, 0)];
+    let armors = [
+        empty,
+        (13, 0, 1),
+        (31, 0, 2),
+        (53, 0, 3),
+        (75, 0, 4),
+        (102, 0, 5),
+    ];
+    let rings = vec![
+        empty,
+        empty,
+        (25, 1, 0),
+        (50, 2, 0),
+        (100, 3, 0),
+        (20, 0, 1),
+        (40, 0, 2),
+        (80, 0, 3),
+    ];
+    let mut winners = Vec::new();
+    let mut losers = Vec::new();
+    for weapon in &weapons {
+        for armor in &armors {
+            for (index, ring_1) in rings.iter().enumerate() {
+                let mut remaining_rings = rings.clone();
+                remaining_rings.swap_remove(index);
+                for ring_2 in remaining_rings {
+                    let cost = weapon.0 + armor.0 + ring_1.0 + ring_2.0;
+                    let damage = weapon.1 + armor.1 + ring_1.1 + ring_2.1;
+                    let protection = weapon.2 + armor.2 + ring_1.2 + ring_2.2;
+                    let player = Fighter::new(100, cost, damage, protection);
+                    let mut arena = Arena::new(player, *boss);
+                    match arena.fight() {
+                        true => winners.push(arena.player.cost),
+                        false => losers.push(arena.player.cost),
+                    };
+                }
+            }
+        }
+    }
+    (winners, losers)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Fighter {
+    health: i32,
+    cost: i32,
+    damage: i32,
+    protection: i32,
+}
+
+impl Fighter {
+    fn new(health: i32, cost: i32, damage: i32, protection: i32) -> Self {
+        Self {
+            health,
+            cost,
+            damage,
+            protection,
+        }
+    }
+
+    fn defend(&mut self, attacker: &Fighter) {
+        self.health -= 1.max(attacker.damage - self.protection)
+    }
+}
+
+#[derive(Debug)]
+struct Arena {
+    player: Fighter,
+    boss: Fighter,
+}
+
+impl Arena {
+    fn new(player: Fighter, boss: Fighter) -> Self {
+        Self { player, boss }
+    }
+
+    fn next_turn(&mut self) {
+        self.boss.defend(&self.player);
+        self.player.defend(&self.boss);
+    }
+
+    fn fight(&mut self) -> bool {
+        loop {
+            self.next_turn();
+            if let Some(winner) = self.winner() {
+                return winner;
+            }
+        }
+    }
+
+    fn winner(&self) -> Option<bool> {
+        if self.boss.health <= 0 {
+            return Some(true);
+        } else if self.player.health <= 0 {
+            return Some(false);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_fight() {
+        let player = Fighter::new(8, 0, 5, 5);
+        let boss = Fighter::new(12, 0, 7, 2);
+        let mut arena = Arena::new(player, boss);
+
+        let expected_player_health = [6, 4, 2, 0];
+        let expected_boss_health = [9, 6, 3, 0];
+        let expected_winner = [None, None, None, Some(true)];
+        for i in 0..4 {
+            arena.next_turn();
+            assert_eq!(arena.player.health, expected_player_health[i]);
+            assert_eq!(arena.boss.health, expected_boss_health[i]);
+            assert_eq!(arena.winner(), expected_winner[i]);
+        }
+    }
+}
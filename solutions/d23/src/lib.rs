@@ -0,0 +1,325 @@
+use std::fmt;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+// Far more than the puzzle programs need, but stops a looping program.
+const STEP_LIMIT: usize = 1_000_000;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_program(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut vm = Vm::new(input).with_limit(STEP_LIMIT);
+        Some(vm.run().ok()?.b)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        let registers = Registers { a: 1, b: 0 };
+        let mut vm = Vm::new(input)
+            .with_registers(registers)
+            .with_limit(STEP_LIMIT);
+        Some(vm.run().ok()?.b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Hlf(Register),
+    Tpl(Register),
+    Inc(Register),
+    Jmp(isize),
+    Jie(Register, isize),
+    Jio(Register, isize),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+}
+
+impl Registers {
+    fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+        }
+    }
+
+    fn get_mut(&mut self, register: Register) -> &mut u64 {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+}
+
+// An executed instruction, with the registers as it left them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmError {
+    StepLimit(usize),
+    Overflow(usize),
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::StepLimit(limit) => write!(f, "stopped after {limit} instructions"),
+            VmError::Overflow(pc) => write!(f, "register overflowed at instruction {pc}"),
+        }
+    }
+}
+
+pub struct Vm<'a> {
+    program: &'a [Instruction],
+    registers: Registers,
+    pc: usize,
+    executed: usize,
+    limit: Option<usize>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Vm {
+            program,
+            registers: Registers::default(),
+            pc: 0,
+            executed: 0,
+            limit: None,
+        }
+    }
+
+    pub fn with_registers(mut self, registers: Registers) -> Self {
+        self.registers = registers;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    // Returns None once the program counter has left the program.
+    pub fn step(&mut self) -> Option<Result<Step, VmError>> {
+        let pc = self.pc;
+        let instruction = *self.program.get(pc)?;
+        if self.limit.is_some_and(|limit| self.executed >= limit) {
+            return Some(Err(VmError::StepLimit(self.executed)));
+        }
+        let offset = match instruction {
+            Instruction::Hlf(register) => {
+                *self.registers.get_mut(register) /= 2;
+                1
+            }
+            Instruction::Tpl(register) => {
+                let value = self.registers.get_mut(register);
+                match value.checked_mul(3) {
+                    Some(tripled) => *value = tripled,
+                    None => return Some(Err(VmError::Overflow(pc))),
+                }
+                1
+            }
+            Instruction::Inc(register) => {
+                let value = self.registers.get_mut(register);
+                match value.checked_add(1) {
+                    Some(incremented) => *value = incremented,
+                    None => return Some(Err(VmError::Overflow(pc))),
+                }
+                1
+            }
+            Instruction::Jmp(offset) => offset,
+            Instruction::Jie(register, offset) => match self.registers.get(register) % 2 {
+                0 => offset,
+                _ => 1,
+            },
+            Instruction::Jio(register, offset) => match self.registers.get(register) {
+                1 => offset,
+                _ => 1,
+            },
+        };
+        // Jumping before the start halts just like running off the end.
+        self.pc = pc.checked_add_signed(offset).unwrap_or(usize::MAX);
+        self.executed += 1;
+        Some(Ok(Step {
+            pc,
+            instruction,
+            registers: self.registers,
+        }))
+    }
+
+    pub fn run(&mut self) -> Result<Registers, VmError> {
+        self.run_with_trace(|_| {})
+    }
+
+    pub fn run_with_trace<F: FnMut(&Step)>(&mut self, mut trace: F) -> Result<Registers, VmError> {
+        while let Some(step) = self.step() {
+            trace(&step?);
+        }
+        Ok(self.registers)
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Register::A => write!(f, "a"),
+            Register::B => write!(f, "b"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Hlf(register) => write!(f, "hlf {register}"),
+            Instruction::Tpl(register) => write!(f, "tpl {register}"),
+            Instruction::Inc(register) => write!(f, "inc {register}"),
+            Instruction::Jmp(offset) => write!(f, "jmp {offset:+}"),
+            Instruction::Jie(register, offset) => write!(f, "jie {register}, {offset:+}"),
+            Instruction::Jio(register, offset) => write!(f, "jio {register}, {offset:+}"),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Registers { a, b } = self.registers;
+        write!(
+            f,
+            "{:>4}: {:<12} a={a} b={b}",
+            self.pc,
+            self.instruction.to_string()
+        )
+    }
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_instruction(line).map_err(|error| error.with_line(idx + 1)))
+        .collect()
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let (operation, operands) = line.split_once(' ').unwrap_or((line, ""));
+    let operands = operands.split(", ").collect::<Vec<_>>();
+    let register = |token| match token {
+        "a" => Ok(Register::A),
+        "b" => Ok(Register::B),
+        _ => Err(ParseError::at(line, token, "register a or b")),
+    };
+    let offset = |token| parse_token(line, token, "an offset");
+    match (operation, &operands[..]) {
+        ("hlf", [r]) => Ok(Instruction::Hlf(register(r)?)),
+        ("tpl", [r]) => Ok(Instruction::Tpl(register(r)?)),
+        ("inc", [r]) => Ok(Instruction::Inc(register(r)?)),
+        ("jmp", [o]) => Ok(Instruction::Jmp(offset(o)?)),
+        ("jie", [r, o]) => Ok(Instruction::Jie(register(r)?, offset(o)?)),
+        ("jio", [r, o]) => Ok(Instruction::Jio(register(r)?, offset(o)?)),
+        ("hlf" | "tpl" | "inc" | "jmp" | "jie" | "jio", _) => Err(ParseError::at(
+            line,
+            line,
+            "the operands of the instruction",
+        )),
+        _ => Err(ParseError::at(line, operation, "an instruction")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Instruction> {
+        parse_program(
+            "inc a
+jio a, +2
+tpl a
+inc a",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_program_examples() {
+        use Instruction::*;
+        use Register::*;
+        assert_eq!(example(), vec![Inc(A), Jio(A, 2), Tpl(A), Inc(A)]);
+        let program = parse_program("hlf b\njmp -7\njie b, +11").unwrap();
+        assert_eq!(program, vec![Hlf(B), Jmp(-7), Jie(B, 11)]);
+    }
+
+    #[test]
+    fn run_examples() {
+        let program = example();
+        assert_eq!(Vm::new(&program).run().unwrap(), Registers { a: 2, b: 0 });
+        let registers = Registers { a: 1, b: 5 };
+        let mut vm = Vm::new(&program).with_registers(registers);
+        // a=2 does not jump, so is tripled and incremented.
+        assert_eq!(vm.run().unwrap(), Registers { a: 7, b: 5 });
+    }
+
+    #[test]
+    fn trace_examples() {
+        let program = example();
+        let mut trace = Vec::new();
+        Vm::new(&program)
+            .run_with_trace(|step| trace.push(step.to_string()))
+            .unwrap();
+        assert_eq!(
+            trace,
+            vec![
+                "   0: inc a        a=1 b=0",
+                "   1: jio a, +2    a=1 b=0",
+                "   3: inc a        a=2 b=0",
+            ]
+        );
+    }
+
+    #[test]
+    fn step_limit() {
+        let program = parse_program("inc b\njmp -1").unwrap();
+        let mut vm = Vm::new(&program).with_limit(100);
+        assert_eq!(vm.run(), Err(VmError::StepLimit(100)));
+        assert_eq!(vm.registers().b, 50);
+    }
+
+    #[test]
+    fn parse_program_errors() {
+        let error = parse_program("inc a\njie c, +2").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (2, 5, "c"));
+        let error = parse_program("jmp two").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 5, "two"));
+        let error = parse_program("dec a").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 1, "dec"));
+        let error = parse_program("jio a").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_core::{InputSource, Solution};
+use d23::Day23;
+
+fn main() {
+    let input = InputSource::from_args(Day23::DAY).read().unwrap();
+    let input = Day23::parse(&input).unwrap();
+    println!("Part 1: {}", Day23::part_1(&input).unwrap());
+    println!("Part 2: {}", Day23::part_2(&input).unwrap());
+}
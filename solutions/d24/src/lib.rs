@@ -0,0 +1,222 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_token(input, line, "a package weight"))
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(best_first_group(input, 3)?.quantum_entanglement)
+    }
+
+    fn part_2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(best_first_group(input, 4)?.quantum_entanglement)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FirstGroup {
+    pub packages: Vec<u64>,
+    pub quantum_entanglement: u64,
+}
+
+// The group in the passenger compartment: as few packages as possible, then
+// the lowest quantum entanglement, and only if the remaining packages really
+// can be split evenly between the other groups.
+pub fn best_first_group(weights: &[u64], groups: usize) -> Option<FirstGroup> {
+    let total = total_weight(weights)?;
+    if groups == 0 || total % groups as u64 != 0 {
+        return None;
+    }
+    let target = total / groups as u64;
+    let mut weights = weights.to_vec();
+    weights.sort_unstable_by(|a, b| b.cmp(a));
+
+    let best = (1..=weights.len()).find_map(|size| {
+        let mut candidates = Vec::new();
+        subsets_of_size(&weights, size, target, &mut Vec::new(), &mut candidates);
+        // Groups too entangled to count come after every other group.
+        candidates.sort_by_key(|packages| {
+            quantum_entanglement(&weights, packages).map_or((true, 0), |qe| (false, qe))
+        });
+        candidates.into_iter().find(|packages| {
+            let rest = weights
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !packages.contains(idx))
+                .map(|(_, weight)| *weight)
+                .collect::<Vec<_>>();
+            can_split(&rest, groups - 1, target)
+        })
+    })?;
+    // The best group can only be reported if its entanglement fits.
+    Some(FirstGroup {
+        quantum_entanglement: quantum_entanglement(&weights, &best)?,
+        packages: best.iter().map(|idx| weights[*idx]).collect(),
+    })
+}
+
+// None when the sum overflows.
+fn total_weight(weights: &[u64]) -> Option<u64> {
+    weights
+        .iter()
+        .try_fold(0u64, |total, weight| total.checked_add(*weight))
+}
+
+// None when the product overflows.
+fn quantum_entanglement(weights: &[u64], packages: &[usize]) -> Option<u64> {
+    packages
+        .iter()
+        .try_fold(1u64, |product, idx| product.checked_mul(weights[*idx]))
+}
+
+// Collects the indices of every `size` package subset of `weights` that
+// weighs exactly `target`, with `weights` sorted heaviest first.
+fn subsets_of_size(
+    weights: &[u64],
+    size: usize,
+    target: u64,
+    chosen: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    if chosen.len() == size {
+        if target == 0 {
+            found.push(chosen.clone());
+        }
+        return;
+    }
+    let start = chosen.last().map_or(0, |last| last + 1);
+    for idx in start..weights.len() {
+        if weights[idx] <= target {
+            chosen.push(idx);
+            subsets_of_size(weights, size, target - weights[idx], chosen, found);
+            chosen.pop();
+        }
+    }
+}
+
+// Whether `weights` can be split into `groups` groups weighing `target` each.
+fn can_split(weights: &[u64], groups: usize, target: u64) -> bool {
+    let expected = target.checked_mul(groups as u64);
+    if expected.is_none() || total_weight(weights) != expected {
+        return false;
+    }
+    let mut used = vec![false; weights.len()];
+    groups == 0 || fill(weights, &mut used, groups, target, target, 0)
+}
+
+// Fills one group at a time, taking packages in order from `start`. Once the
+// sums check out, the last group is whatever is left over.
+fn fill(
+    weights: &[u64],
+    used: &mut [bool],
+    groups: usize,
+    target: u64,
+    remaining: u64,
+    start: usize,
+) -> bool {
+    if groups == 1 {
+        return true;
+    }
+    if remaining == 0 {
+        return fill(weights, used, groups - 1, target, target, 0);
+    }
+    for idx in start..weights.len() {
+        if used[idx] || weights[idx] > remaining {
+            continue;
+        }
+        used[idx] = true;
+        if fill(
+            weights,
+            used,
+            groups,
+            target,
+            remaining - weights[idx],
+            idx + 1,
+        ) {
+            return true;
+        }
+        used[idx] = false;
+        // The first unused package has to go in some group, so a new group
+        // may as well start with it rather than trying every other package.
+        if remaining == target {
+            break;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<u64> {
+        vec![1, 2, 3, 4, 5, 7, 8, 9, 10, 11]
+    }
+
+    #[test]
+    fn three_groups_example() {
+        let group = best_first_group(&example(), 3).unwrap();
+        assert_eq!(group.packages, vec![11, 9]);
+        assert_eq!(group.quantum_entanglement, 99);
+    }
+
+    #[test]
+    fn four_groups_example() {
+        let group = best_first_group(&example(), 4).unwrap();
+        assert_eq!(group.packages, vec![11, 4]);
+        assert_eq!(group.quantum_entanglement, 44);
+    }
+
+    #[test]
+    fn remaining_packages_are_verified() {
+        // 13 alone balances the sleigh, but 2, 4, 6 and 14 cannot make two 13s.
+        assert_eq!(best_first_group(&[2, 4, 6, 13, 14], 3), None);
+        assert!(!can_split(&[2, 4, 6, 14], 2, 13));
+        assert!(can_split(&[10, 8, 7, 5, 4, 3, 2, 1], 2, 20));
+        assert!(can_split(&[10, 8, 7, 5, 4, 3, 2, 1], 4, 10));
+        assert!(!can_split(&[5, 5, 2], 2, 6));
+    }
+
+    #[test]
+    fn entanglement_overflow() {
+        // Both kinds of pair weigh 2^33 + 2, but 2^32 + 1 squared overflows.
+        let big = (1 << 32) + 1;
+        let weights = [1 << 33, 2, big, big, 1 << 33, 2];
+        let group = best_first_group(&weights, 3).unwrap();
+        assert_eq!(group.packages, vec![1 << 33, 2]);
+        assert_eq!(group.quantum_entanglement, 1 << 34);
+        assert_eq!(best_first_group(&[big; 6], 3), None);
+    }
+
+    #[test]
+    fn weight_overflow() {
+        let third = u64::MAX / 3;
+        let group = best_first_group(&[third; 3], 3).unwrap();
+        assert_eq!(group.packages, vec![third]);
+        assert_eq!(group.quantum_entanglement, third);
+        assert_eq!(best_first_group(&[u64::MAX, 1, 2], 3), None);
+        assert!(!can_split(&[u64::MAX, 1], 2, u64::MAX));
+        assert!(!can_split(&[1, 1], 2, u64::MAX));
+    }
+
+    #[test]
+    fn any_number_of_groups() {
+        let weights = (1..=12).collect::<Vec<_>>();
+        let group = best_first_group(&weights, 6).unwrap();
+        assert_eq!(group.packages, vec![12, 1]);
+        assert_eq!(best_first_group(&weights, 1).unwrap().packages.len(), 12);
+        assert_eq!(best_first_group(&weights, 5), None);
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_core::{InputSource, Solution};
+use d24::Day24;
+
+fn main() {
+    let input = InputSource::from_args(Day24::DAY).read().unwrap();
+    let input = Day24::parse(&input).unwrap();
+    println!("Part 1: {}", Day24::part_1(&input).unwrap());
+    println!("Part 2: {}", Day24::part_2(&input).unwrap());
+}
@@ -0,0 +1,162 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Position;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_position(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(code_at(input.row, input.column))
+    }
+
+    // The last day only has the one puzzle.
+    fn part_2(_input: &Self::Input) -> Option<Self::Part2> {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Position {
+    row: u64,
+    column: u64,
+}
+
+fn parse_position(input: &str) -> Result<Position, ParseError> {
+    let words = input.split_whitespace().collect::<Vec<_>>();
+    let number_after = |label: &str, end: char| {
+        let idx = words
+            .iter()
+            .position(|word| *word == label)
+            .ok_or_else(|| ParseError::at(input, input.trim(), &format!("'{label} N'")))?;
+        let token = words.get(idx + 1).copied().unwrap_or(&input[input.len()..]);
+        let number = token.strip_suffix(end).unwrap_or(token);
+        let position = parse_token::<u64>(input, number, "a number of at least 1")?;
+        match position {
+            0 => Err(ParseError::at(input, number, "a number of at least 1")),
+            position => Ok(position),
+        }
+    };
+    Ok(Position {
+        row: number_after("row", ',')?,
+        column: number_after("column", '.')?,
+    })
+}
+
+// Codes are filled in along diagonals, starting at the bottom left of each:
+// (1, 1), then (2, 1), (1, 2), then (3, 1), (2, 2), (1, 3) and so on. Far
+// enough out the index no longer fits in a u64, so it is only ever worked out
+// modulo `period`.
+fn index_of(row: u64, column: u64, period: u64) -> u64 {
+    let (row, column, period) = (u128::from(row), u128::from(column), u128::from(period));
+    let diagonal = row + column - 1;
+    // Halving whichever of the two is even keeps the division exact.
+    let (even, odd) = if diagonal % 2 == 0 {
+        (diagonal, diagonal - 1)
+    } else {
+        (diagonal - 1, diagonal)
+    };
+    let before = (even / 2 % period) * (odd % period) % period;
+    ((before + column - 1) % period) as u64
+}
+
+// Each code is the previous one times MULTIPLIER, so the nth code only
+// needs MULTIPLIER to the nth power. MODULUS is prime, so by Fermat that
+// power repeats every MODULUS - 1 codes.
+fn code_at(row: u64, column: u64) -> u64 {
+    let index = index_of(row, column, MODULUS - 1);
+    FIRST_CODE * mod_pow(MULTIPLIER, index, MODULUS) % MODULUS
+}
+
+fn mod_pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_table() -> [[u64; 6]; 6] {
+        [
+            [20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
+            [31916031, 21629792, 16929656, 7726640, 15514188, 4041754],
+            [16080970, 8057251, 1601130, 7981243, 11661866, 16474243],
+            [24592653, 32451966, 21345942, 9380097, 10600672, 31527494],
+            [77061, 17552253, 28094349, 6899651, 9250759, 31663883],
+            [33071741, 6796745, 25397450, 24659492, 1534922, 27995004],
+        ]
+    }
+
+    #[test]
+    fn index_of_examples() {
+        let indices = [(1, 1), (2, 1), (1, 2), (3, 1), (2, 2), (1, 3), (4, 1)]
+            .into_iter()
+            .map(|(row, column)| index_of(row, column, u64::MAX))
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn code_at_examples() {
+        for (row, codes) in (1..).zip(example_table()) {
+            for (column, code) in (1..).zip(codes) {
+                assert_eq!(code_at(row, column), code, "row {row}, column {column}");
+            }
+        }
+    }
+
+    #[test]
+    fn code_at_matches_iterating() {
+        let mut code = FIRST_CODE;
+        for _ in 0..index_of(40, 40, u64::MAX) {
+            code = code * MULTIPLIER % MODULUS;
+        }
+        assert_eq!(code_at(40, 40), code);
+    }
+
+    #[test]
+    fn code_at_large_positions() {
+        assert_eq!(code_at(u64::MAX, 2), 18043583);
+        assert_eq!(code_at(u64::MAX, u64::MAX), 7982616);
+        assert_eq!(code_at(1, u64::MAX), 31511490);
+        assert_eq!(index_of(3, 1, 2), 1);
+    }
+
+    #[test]
+    fn parse_position_examples() {
+        let input = "To continue, please consult the code grid in the manual.  \
+Enter the code at row 2981, column 3075.\n";
+        assert_eq!(
+            parse_position(input).unwrap(),
+            Position {
+                row: 2981,
+                column: 3075
+            }
+        );
+        let error = parse_position("Enter the code at row 0, column 1.").unwrap_err();
+        assert_eq!((error.column(), error.token()), (23, "0"));
+        let error = parse_position("Enter the code at row 5, column x.").unwrap_err();
+        assert_eq!((error.column(), error.token()), (33, "x"));
+        assert!(parse_position("Enter the code at row 5.").is_err());
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_core::{InputSource, Solution};
+use d25::Day25;
+
+fn main() {
+    let input = InputSource::from_args(Day25::DAY).read().unwrap();
+    let input = Day25::parse(&input).unwrap();
+    println!("Part 1: {}", Day25::part_1(&input).unwrap());
+}